@@ -0,0 +1,241 @@
+use std::collections::{hash_map::Entry, HashMap, HashSet, VecDeque};
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    braced, bracketed,
+    parse::{Parse, ParseStream, Result},
+    punctuated::Punctuated,
+    Error, Ident, Token, Visibility,
+};
+
+// The state with its transitions: `Loading => [Ready, Failed]`
+struct StateDecl {
+    name: Ident,
+    transitions: Vec<Ident>,
+}
+
+impl Parse for StateDecl {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name: Ident = input.parse()?;
+
+        let mut transitions = Vec::new();
+        if input.peek(Token![=>]) {
+            let _: Token![=>] = input.parse()?;
+
+            let content;
+            bracketed!(content in input);
+            transitions = Punctuated::<Ident, Token![,]>::parse_terminated(&content)?
+                .into_iter()
+                .collect();
+        }
+
+        Ok(StateDecl { name, transitions })
+    }
+}
+
+/// The finite state machine declaration.
+///
+/// Either `vis fn name { states }` or just the states, built with the private `fsm` function.
+pub struct FsmDef {
+    vis: Visibility,
+    name: Ident,
+    states: Vec<StateDecl>,
+}
+
+impl Parse for FsmDef {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let vis: Visibility = input.parse()?;
+
+        if input.peek(Token![fn]) {
+            let _: Token![fn] = input.parse()?;
+            let name: Ident = input.parse()?;
+
+            let content;
+            braced!(content in input);
+            let states = parse_states(&content)?;
+
+            return Ok(FsmDef { vis, name, states });
+        }
+
+        if let Visibility::Inherited = vis {
+            let states = parse_states(input)?;
+            return Ok(FsmDef {
+                vis,
+                name: format_ident!("fsm"),
+                states,
+            });
+        }
+
+        Err(input.error("expected `fn` after the visibility"))
+    }
+}
+
+fn parse_states(input: ParseStream) -> Result<Vec<StateDecl>> {
+    Ok(Punctuated::<StateDecl, Token![,]>::parse_terminated(input)?
+        .into_iter()
+        .collect())
+}
+
+impl FsmDef {
+    // Check the duplicate, undeclared and unreachable states
+    fn validate(&self) -> Result<()> {
+        let mut errors: Vec<Error> = Vec::new();
+
+        let initial = match self.states.first() {
+            Some(initial) => &initial.name,
+            None => return Err(Error::new(self.name.span(), "the state machine has no states")),
+        };
+
+        let mut declared = HashMap::new();
+        for state in &self.states {
+            match declared.entry(state.name.to_string()) {
+                Entry::Occupied(_) => errors.push(Error::new(
+                    state.name.span(),
+                    format!("the state `{}` is declared several times", state.name),
+                )),
+                Entry::Vacant(entry) => {
+                    entry.insert(state);
+                }
+            }
+        }
+
+        for target in self.states.iter().flat_map(|state| &state.transitions) {
+            if !declared.contains_key(&target.to_string()) {
+                errors.push(Error::new(
+                    target.span(),
+                    format!("the transition to the undeclared state `{}`", target),
+                ));
+            }
+        }
+
+        // walk the transitions from the initial state
+        let mut reachable = HashSet::new();
+        let mut queue = VecDeque::new();
+        reachable.insert(initial.to_string());
+        queue.push_back(initial.to_string());
+
+        while let Some(name) = queue.pop_front() {
+            for target in &declared[&name].transitions {
+                let target = target.to_string();
+                if declared.contains_key(&target) && reachable.insert(target.clone()) {
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        for state in &self.states {
+            if !reachable.contains(&state.name.to_string()) {
+                errors.push(Error::new(
+                    state.name.span(),
+                    format!("the state `{}` is unreachable from the initial state `{}`", state.name, initial),
+                ));
+            }
+        }
+
+        match errors.into_iter().reduce(|mut error, next| {
+            error.combine(next);
+            error
+        }) {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Expand the declaration to the state types and the function building the machine
+pub fn expand(def: FsmDef) -> Result<TokenStream> {
+    def.validate()?;
+
+    let FsmDef { vis, name, states } = def;
+    let initial = &states[0].name;
+
+    let types = states.iter().map(|state| {
+        let state = &state.name;
+        let doc = format!("The `{}` state of the `{}` machine", state, name);
+
+        quote! {
+            #[doc = #doc]
+            #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+            #vis struct #state;
+
+            impl ::ruex::foundation::patterns::fsm::Typed for #state {
+                fn type_id(&self) -> ::std::any::TypeId {
+                    ::std::any::TypeId::of::<Self>()
+                }
+            }
+
+            impl<T> ::ruex::foundation::patterns::fsm::State<T> for #state
+            where
+                T: ::ruex::foundation::patterns::fsm::FsmIntegration<T>,
+            {
+            }
+        }
+    });
+
+    let adds = states.iter().map(|state| {
+        let transitions = &state.transitions;
+        let state = &state.name;
+
+        quote! {
+            fsm.add(
+                #state,
+                ::ruex::foundation::patterns::fsm::Transitions::new() #(.allow(#transitions))*,
+            )
+            .expect("the states are checked by the fsm! macro");
+        }
+    });
+
+    let doc = format!("Build the `{}` machine, entered to the `{}` state", name, initial);
+
+    Ok(quote! {
+        #(#types)*
+
+        #[doc = #doc]
+        #vis fn #name<T>(integration: T) -> ::ruex::foundation::patterns::fsm::Fsm<T>
+        where
+            T: ::ruex::foundation::patterns::fsm::FsmIntegration<T>,
+        {
+            let fsm = ::ruex::foundation::patterns::fsm::Fsm::new(integration);
+            #(#adds)*
+            fsm.goto(#initial).expect("the states are checked by the fsm! macro");
+            fsm
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand, FsmDef};
+
+    fn expand_str(input: &str) -> Result<String, String> {
+        let def: FsmDef = syn::parse_str(input).map_err(|error| error.to_string())?;
+        expand(def)
+            .map(|tokens| tokens.to_string())
+            .map_err(|error| error.into_iter().map(|error| error.to_string()).collect::<Vec<_>>().join("; "))
+    }
+
+    #[test]
+    fn should_expand_valid_machine() {
+        let tokens = expand_str("pub fn loader { Idle => [Loading], Loading => [Ready, Idle], Ready }").unwrap();
+
+        assert!(tokens.contains("pub struct Loading"));
+        assert!(tokens.contains("pub fn loader"));
+        assert!(expand_str("Idle => [Busy], Busy => [Idle]").unwrap().contains("fn fsm"));
+    }
+
+    #[test]
+    fn should_reject_invalid_machine() {
+        assert_eq!(
+            expand_str("Idle => [Loading], Idle => [], Loading => [Ready]"),
+            Err("the state `Idle` is declared several times; \
+                 the transition to the undeclared state `Ready`"
+                .into())
+        );
+
+        assert_eq!(
+            expand_str("Idle => [Loading], Loading, Failed => [Idle]"),
+            Err("the state `Failed` is unreachable from the initial state `Idle`".into())
+        );
+    }
+}
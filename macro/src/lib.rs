@@ -9,6 +9,9 @@ use syn::{
     DeriveInput, Ident, ItemFn, Token,
 };
 
+mod fsm;
+mod state;
+
 struct AdviceField {
     member: Ident,
     // colon: Token![:],
@@ -59,6 +62,64 @@ pub fn Aspect(attr: TokenStream, item: TokenStream) -> TokenStream {
     item
 }
 
+/// Declare the finite state machine.
+///
+/// Generates the unit type of each state with its `Typed` and `State` impls,
+/// and the function building the machine, entered to the first state.
+///
+/// ```ignore
+/// fsm! {
+///     pub fn loader {
+///         Idle => [Loading],
+///         Loading => [Ready, Failed],
+///         Ready,
+///         Failed => [Idle],
+///     }
+/// }
+///
+/// let fsm = loader(CallbackIntegration::new());
+/// fsm.goto(Loading)?;
+/// ```
+///
+/// Without the `fn` header the function is the private `fsm`.
+/// The duplicate states, the transitions to the undeclared states and the states
+/// unreachable from the first one are rejected at compile time.
+#[proc_macro]
+pub fn fsm(input: TokenStream) -> TokenStream {
+    let def = parse_macro_input!(input as fsm::FsmDef);
+    fsm::expand(def).unwrap_or_else(|error| error.to_compile_error()).into()
+}
+
+/// Derive the `Typed` of the FSM state
+#[proc_macro_derive(Typed)]
+pub fn derive_typed(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    state::expand_typed(input).unwrap_or_else(|error| error.to_compile_error()).into()
+}
+
+/// Derive the `State` of the FSM, the state should also derive `Debug` and `Typed`.
+///
+/// The handler methods are bound with the `state` attribute:
+///
+/// ```ignore
+/// #[derive(Debug, Typed, State)]
+/// #[state(enter = "on_enter", exit = "on_exit", integration = "CallbackIntegration")]
+/// struct Loading;
+///
+/// impl Loading {
+///     fn on_enter(&self, target: &CallbackIntegration) {}
+///     fn on_exit(&self, target: &CallbackIntegration) {}
+/// }
+/// ```
+///
+/// Without the `integration` the state is implemented for any of them,
+/// so the handlers should be generic over the integration.
+#[proc_macro_derive(State, attributes(state))]
+pub fn derive_state(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    state::expand_state(input).unwrap_or_else(|error| error.to_compile_error()).into()
+}
+
 #[cfg(test)]
 mod tests {
     #[test]
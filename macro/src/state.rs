@@ -0,0 +1,111 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, DeriveInput, Error, GenericParam, Ident, Lit, Meta, NestedMeta, Path, Result, TypeParam};
+
+/// Expand `#[derive(Typed)]`
+pub fn expand_typed(input: DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::ruex::foundation::patterns::fsm::Typed for #name #ty_generics #where_clause {
+            fn type_id(&self) -> ::std::any::TypeId {
+                ::std::any::TypeId::of::<Self>()
+            }
+        }
+    })
+}
+
+// The arguments of the `#[state(...)]` attribute
+#[derive(Default)]
+struct StateArgs {
+    enter: Option<Ident>,
+    exit: Option<Ident>,
+    integration: Option<Path>,
+}
+
+impl StateArgs {
+    fn parse(input: &DeriveInput) -> Result<Self> {
+        let mut args = StateArgs::default();
+
+        for attr in input.attrs.iter().filter(|attr| attr.path.is_ident("state")) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(Error::new_spanned(meta, "expected `#[state(enter = \"...\", ...)]`")),
+            };
+
+            for nested in list.nested {
+                let pair = match nested {
+                    NestedMeta::Meta(Meta::NameValue(pair)) => pair,
+                    nested => return Err(Error::new_spanned(nested, "expected `key = \"value\"`")),
+                };
+
+                let value = match &pair.lit {
+                    Lit::Str(value) => value,
+                    lit => return Err(Error::new_spanned(lit, "expected the string literal")),
+                };
+
+                if pair.path.is_ident("enter") {
+                    args.enter = Some(value.parse()?);
+                } else if pair.path.is_ident("exit") {
+                    args.exit = Some(value.parse()?);
+                } else if pair.path.is_ident("integration") {
+                    args.integration = Some(value.parse()?);
+                } else {
+                    return Err(Error::new_spanned(
+                        pair.path,
+                        "unknown key, expected `enter`, `exit` or `integration`",
+                    ));
+                }
+            }
+        }
+
+        Ok(args)
+    }
+}
+
+/// Expand `#[derive(State)]`
+pub fn expand_state(input: DeriveInput) -> Result<TokenStream> {
+    let args = StateArgs::parse(&input)?;
+    let name = &input.ident;
+    let (_, ty_generics, _) = input.generics.split_for_impl();
+
+    // the state of the integration, or of any of them
+    let mut generics = input.generics.clone();
+    let integration = match args.integration {
+        Some(integration) => quote!(#integration),
+        None => {
+            let param: TypeParam = parse_quote!(__Integration);
+            generics.params.push(GenericParam::Type(param));
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(__Integration: ::ruex::foundation::patterns::fsm::FsmIntegration<__Integration>));
+            quote!(__Integration)
+        }
+    };
+    let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+    let enter = args.enter.map(|enter| {
+        quote! {
+            fn enter(&self, target: &#integration) {
+                self.#enter(target)
+            }
+        }
+    });
+
+    let exit = args.exit.map(|exit| {
+        quote! {
+            fn exit(&self, target: &#integration) {
+                self.#exit(target)
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::ruex::foundation::patterns::fsm::State<#integration> for #name #ty_generics #where_clause {
+            #enter
+            #exit
+        }
+    })
+}
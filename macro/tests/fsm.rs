@@ -0,0 +1,31 @@
+use ruex::foundation::patterns::fsm::{CallbackIntegration, FsmError};
+use ruex_macro::fsm;
+
+fsm! {
+    fn loader {
+        Idle => [Loading],
+        Loading => [Ready, Failed],
+        Ready,
+        Failed => [Idle],
+    }
+}
+
+#[test]
+fn should_build_declared_machine() -> Result<(), FsmError> {
+    let fsm = loader(CallbackIntegration::new());
+    assert!(fsm.current_state::<Idle>()?);
+
+    fsm.goto(Loading)?;
+    fsm.goto(Failed)?;
+    assert_eq!(
+        fsm.goto(Ready),
+        Err(FsmError::TransitionNotAllowed {
+            state: "Failed".into(),
+            input: "Ready".into()
+        })
+    );
+
+    fsm.goto(Idle)?;
+    assert_eq!(fsm.current_state_name()?, "Idle");
+    Ok(())
+}
@@ -0,0 +1,54 @@
+use std::cell::RefCell;
+
+use ruex::foundation::patterns::fsm::{CallbackIntegration, Fsm, FsmError, FsmIntegration, Transitions};
+use ruex_macro::{State, Typed};
+
+thread_local! {
+    static LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+}
+
+fn log(entry: &str) {
+    LOG.with(|log| log.borrow_mut().push(entry.into()));
+}
+
+#[derive(Debug, Typed, State)]
+#[state(enter = "on_enter", exit = "on_exit", integration = "CallbackIntegration")]
+struct Loading;
+
+impl Loading {
+    fn on_enter(&self, _target: &CallbackIntegration) {
+        log("enter Loading");
+    }
+
+    fn on_exit(&self, _target: &CallbackIntegration) {
+        log("exit Loading");
+    }
+}
+
+#[derive(Debug, Typed, State)]
+#[state(enter = "on_enter")]
+struct Ready;
+
+impl Ready {
+    fn on_enter<T: FsmIntegration<T>>(&self, _target: &T) {
+        log("enter Ready");
+    }
+}
+
+#[derive(Debug, Typed, State)]
+struct Failed;
+
+#[test]
+fn should_call_bound_handlers() -> Result<(), FsmError> {
+    let fsm = Fsm::new(CallbackIntegration::new());
+    fsm.add(Loading, Transitions::new().allow(Ready).allow(Failed))?;
+    fsm.add(Ready, vec![])?;
+    fsm.add(Failed, vec![])?;
+
+    fsm.goto(Loading)?;
+    fsm.goto(Ready)?;
+    assert!(fsm.current_state::<Ready>()?);
+
+    LOG.with(|log| assert_eq!(*log.borrow(), vec!["enter Loading", "exit Loading", "enter Ready"]));
+    Ok(())
+}
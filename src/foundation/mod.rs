@@ -30,7 +30,7 @@
 //! 
 //! ## Facade & Core
 //! 
-//! The [Facade][4], another Singleton, initializes the Core actors ([Model][2],
+//! The [Facade][4], another Multiton, initializes the Core actors ([Model][2],
 //! [View][3] and [Controller][1]), and provides a single place to access all of
 //! their public methods.
 //! 
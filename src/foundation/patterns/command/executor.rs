@@ -0,0 +1,175 @@
+use std::{
+    collections::VecDeque,
+    fmt,
+    future::Future,
+    pin::Pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard, PoisonError,
+    },
+    task::{Context, Poll, Wake, Waker},
+    thread::{self, Thread},
+};
+
+use crate::prelude::{BoxFuture, Spawner};
+
+// Wakes the thread which drives the futures
+struct ThreadWaker {
+    thread: Thread,
+    woken: AtomicBool,
+}
+
+impl ThreadWaker {
+    fn current() -> Arc<Self> {
+        Arc::new(Self {
+            thread: thread::current(),
+            woken: AtomicBool::new(false),
+        })
+    }
+
+    // Park the thread until the waker is woken
+    fn park(&self) {
+        while !self.woken.swap(false, Ordering::SeqCst) {
+            thread::park();
+        }
+    }
+}
+
+impl Wake for ThreadWaker {
+    fn wake(self: Arc<Self>) {
+        self.wake_by_ref();
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.woken.store(true, Ordering::SeqCst);
+        self.thread.unpark();
+    }
+}
+
+/// Run a future to completion on the current thread.
+///
+/// The thread is parked while the future is pending.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = Box::pin(future);
+
+    let waker = ThreadWaker::current();
+    let context_waker = Waker::from(waker.clone());
+    let mut cx = Context::from_waker(&context_waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+
+        waker.park();
+    }
+}
+
+/// Future which resolves when all of its futures are completed.
+///
+/// Created with [join_all].
+pub struct JoinAll {
+    futures: Vec<BoxFuture<'static, ()>>,
+}
+
+/// Create a future which resolves when all of the given futures are completed.
+pub fn join_all(futures: Vec<BoxFuture<'static, ()>>) -> JoinAll {
+    JoinAll { futures }
+}
+
+impl Future for JoinAll {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.futures.retain_mut(|future| future.as_mut().poll(cx).is_pending());
+
+        if self.futures.is_empty() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+impl fmt::Debug for JoinAll {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinAll").field("pending", &self.futures.len()).finish()
+    }
+}
+
+/// A minimal single threaded [Spawner].
+///
+/// The spawned futures are queued until [run](LocalExecutor::run) is called,
+/// which makes the execution of the [AsyncCommand]'s deterministic, e.g. in tests.
+///
+/// [AsyncCommand]: crate::prelude::AsyncCommand
+#[derive(Default)]
+pub struct LocalExecutor {
+    tasks: Mutex<VecDeque<BoxFuture<'static, ()>>>,
+}
+
+impl LocalExecutor {
+    /// Create new LocalExecutor
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieve the number of the spawned futures, which are not completed yet
+    pub fn len(&self) -> usize {
+        self.tasks().len()
+    }
+
+    /// Check if all of the spawned futures are completed
+    pub fn is_empty(&self) -> bool {
+        self.tasks().is_empty()
+    }
+
+    /// Drive the spawned futures on the current thread until all of them are completed.
+    ///
+    /// The futures are polled in turns, so the futures spawned meanwhile are driven as well.
+    pub fn run(&self) {
+        let waker = ThreadWaker::current();
+        let context_waker = Waker::from(waker.clone());
+        let mut cx = Context::from_waker(&context_waker);
+
+        loop {
+            // the queue is not locked while polling, as the futures may spawn other futures
+            let tasks = std::mem::take(&mut *self.tasks());
+            if tasks.is_empty() {
+                break;
+            }
+
+            let polled = tasks.len();
+            let pending = tasks
+                .into_iter()
+                .filter_map(|mut task| task.as_mut().poll(&mut cx).is_pending().then_some(task))
+                .collect::<Vec<_>>();
+
+            let mut queue = self.tasks();
+            let spawned = !queue.is_empty();
+            let stalled = pending.len() == polled && !spawned;
+            queue.extend(pending);
+            drop(queue);
+
+            if stalled {
+                waker.park();
+            }
+        }
+    }
+
+    fn tasks(&self) -> MutexGuard<'_, VecDeque<BoxFuture<'static, ()>>> {
+        self.tasks.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl Spawner for LocalExecutor {
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        self.tasks().push_back(future);
+    }
+}
+
+impl fmt::Debug for LocalExecutor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LocalExecutor").field("pending", &self.len()).finish()
+    }
+}
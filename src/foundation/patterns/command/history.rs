@@ -0,0 +1,363 @@
+use std::{collections::VecDeque, fmt};
+
+use crate::prelude::{Lock, Notification, Shared, UndoableCommand};
+
+/// Default number of transactions kept by the [CommandHistory]
+pub const DEFAULT_HISTORY_DEPTH: usize = 100;
+
+// Executed command with the notification it has handled
+struct HistoryEntry<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    command: Shared<dyn UndoableCommand<Body>>,
+    notification: Shared<dyn Notification<Body>>,
+}
+
+impl<Body> Clone for HistoryEntry<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            command: self.command.clone(),
+            notification: self.notification.clone(),
+        }
+    }
+}
+
+type Transaction<Body> = Vec<HistoryEntry<Body>>;
+
+struct HistoryState<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    undo: VecDeque<Transaction<Body>>,
+    redo: Vec<Transaction<Body>>,
+    max_depth: usize,
+
+    // Transaction collecting the entries, and its nesting level
+    transaction: Option<Transaction<Body>>,
+    transaction_level: usize,
+
+    // Nesting level of executions, undos and redos in progress,
+    // the commands they trigger are not recorded
+    busy: usize,
+}
+
+impl<Body> HistoryState<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn push(&mut self, transaction: Transaction<Body>) {
+        if transaction.is_empty() {
+            return;
+        }
+
+        self.undo.push_back(transaction);
+        self.redo.clear();
+        self.trim();
+    }
+
+    fn trim(&mut self) {
+        while self.undo.len() > self.max_depth {
+            self.undo.pop_front();
+        }
+    }
+}
+
+/// The undo/redo history of the [UndoableCommand]'s.
+///
+/// The [Controller] records each [UndoableCommand] it executes, together with
+/// the [Notification] it has handled. Several executions are grouped into
+/// one transaction between [begin_transaction](CommandHistory::begin_transaction)
+/// and [commit_transaction](CommandHistory::commit_transaction), so they are undone
+/// and redone at once.
+///
+/// The [UndoableCommand]'s triggered by the execution, undo or redo of another one
+/// are not recorded, as the outer [UndoableCommand] is responsible for them.
+///
+/// [Controller]: crate::prelude::Controller
+pub struct CommandHistory<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    state: Lock<HistoryState<Body>>,
+}
+
+impl<Body> CommandHistory<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    /// Create new CommandHistory with [DEFAULT_HISTORY_DEPTH]
+    pub fn new() -> Self {
+        Self::with_max_depth(DEFAULT_HISTORY_DEPTH)
+    }
+
+    /// Create new CommandHistory which keeps at most `max_depth` transactions
+    pub fn with_max_depth(max_depth: usize) -> Self {
+        Self {
+            state: Lock::new(HistoryState {
+                undo: VecDeque::new(),
+                redo: Vec::new(),
+                max_depth,
+                transaction: None,
+                transaction_level: 0,
+                busy: 0,
+            }),
+        }
+    }
+
+    /// Retrieve the maximal number of transactions kept by the history
+    pub fn max_depth(&self) -> usize {
+        self.state.borrow().max_depth
+    }
+
+    /// Set the maximal number of transactions kept by the history,
+    /// the oldest transactions are dropped
+    pub fn set_max_depth(&self, max_depth: usize) {
+        let mut state = self.state.borrow_mut();
+        state.max_depth = max_depth;
+        state.trim();
+    }
+
+    /// Check if there is a transaction to undo
+    pub fn can_undo(&self) -> bool {
+        !self.state.borrow().undo.is_empty()
+    }
+
+    /// Check if there is a transaction to redo
+    pub fn can_redo(&self) -> bool {
+        !self.state.borrow().redo.is_empty()
+    }
+
+    /// Undo the last transaction, returns `false` if there is nothing to undo.
+    ///
+    /// The commands of the transaction are undone in the reverse order.
+    pub fn undo(&self) -> bool {
+        let transaction = match self.state.borrow_mut().undo.pop_back() {
+            Some(transaction) => transaction,
+            None => return false,
+        };
+
+        self.replay(|| {
+            for entry in transaction.iter().rev() {
+                entry.command.undo(entry.notification.clone());
+            }
+        });
+
+        self.state.borrow_mut().redo.push(transaction);
+        true
+    }
+
+    /// Redo the last undone transaction, returns `false` if there is nothing to redo.
+    pub fn redo(&self) -> bool {
+        let transaction = match self.state.borrow_mut().redo.pop() {
+            Some(transaction) => transaction,
+            None => return false,
+        };
+
+        self.replay(|| {
+            for entry in transaction.iter() {
+                entry.command.redo(entry.notification.clone());
+            }
+        });
+
+        let mut state = self.state.borrow_mut();
+        state.undo.push_back(transaction);
+        state.trim();
+        true
+    }
+
+    /// Start grouping the recorded commands into one transaction.
+    ///
+    /// The transactions are able to be nested, the outermost one is recorded.
+    pub fn begin_transaction(&self) {
+        let mut state = self.state.borrow_mut();
+        state.transaction_level += 1;
+        state.transaction.get_or_insert_with(Vec::new);
+    }
+
+    /// Finish the transaction started with [begin_transaction](CommandHistory::begin_transaction)
+    pub fn commit_transaction(&self) {
+        let mut state = self.state.borrow_mut();
+        if state.transaction_level == 0 {
+            log::error!("Commit of not started transaction [CommandHistory]");
+            return;
+        }
+
+        state.transaction_level -= 1;
+        if state.transaction_level == 0 {
+            if let Some(transaction) = state.transaction.take() {
+                state.push(transaction);
+            }
+        }
+    }
+
+    /// Group the commands recorded while `f` is called into one transaction
+    pub fn transaction<R>(&self, f: impl FnOnce() -> R) -> R {
+        self.begin_transaction();
+        let result = f();
+        self.commit_transaction();
+        result
+    }
+
+    /// Forget all of the recorded transactions
+    pub fn clear(&self) {
+        let mut state = self.state.borrow_mut();
+        state.undo.clear();
+        state.redo.clear();
+    }
+
+    /// Execute the [UndoableCommand] and record it.
+    pub fn execute(&self, command: Shared<dyn UndoableCommand<Body>>, notification: Shared<dyn Notification<Body>>) {
+        let recorded = self.state.borrow().busy == 0;
+
+        self.replay(|| command.execute(notification.clone()));
+
+        if recorded {
+            let entry = HistoryEntry { command, notification };
+
+            let mut state = self.state.borrow_mut();
+            match state.transaction.as_mut() {
+                Some(transaction) => transaction.push(entry),
+                None => state.push(vec![entry]),
+            }
+        }
+    }
+
+    // The state is not borrowed while `f` is called,
+    // as the commands may send notifications meanwhile
+    fn replay(&self, f: impl FnOnce()) {
+        self.state.borrow_mut().busy += 1;
+        f();
+        self.state.borrow_mut().busy -= 1;
+    }
+}
+
+impl<Body> Default for CommandHistory<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Body> fmt::Debug for CommandHistory<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let state = self.state.borrow();
+        f.debug_struct("CommandHistory")
+            .field("undo", &state.undo.len())
+            .field("redo", &state.redo.len())
+            .field("max_depth", &state.max_depth)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    const ADD: Interest = Interest(1);
+
+    #[derive(Debug, Default)]
+    struct AddCommand {
+        total: Shared<AtomicU32>,
+    }
+
+    impl UndoableCommand<u32> for AddCommand {
+        fn execute(&self, notification: Shared<dyn Notification<u32>>) {
+            self.total
+                .fetch_add(notification.body().copied().unwrap_or_default(), Ordering::SeqCst);
+        }
+
+        fn undo(&self, notification: Shared<dyn Notification<u32>>) {
+            self.total
+                .fetch_sub(notification.body().copied().unwrap_or_default(), Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn should_undo_and_redo_sent_commands() {
+        let facade = BaseFacade::<u32>::instance("undo-redo");
+
+        let command = Shared::new(AddCommand::default());
+        let total = command.total.clone();
+        facade.register_undoable_command(ADD, command);
+
+        facade.send(ADD, Some(2));
+        facade.send(ADD, Some(3));
+        assert_eq!(total.load(Ordering::SeqCst), 5);
+
+        let history = facade.history();
+        assert!(history.undo());
+        assert_eq!(total.load(Ordering::SeqCst), 2);
+        assert!(history.can_redo());
+
+        assert!(history.redo());
+        assert_eq!(total.load(Ordering::SeqCst), 5);
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert_eq!(total.load(Ordering::SeqCst), 0);
+        assert!(!history.can_undo());
+        assert!(!history.undo());
+
+        // a new command drops the undone ones
+        facade.send(ADD, Some(7));
+        assert!(!history.can_redo());
+
+        BaseFacade::<u32>::remove_core("undo-redo");
+    }
+
+    #[test]
+    fn should_group_commands_into_transaction() {
+        let facade = BaseFacade::<u32>::instance("undo-transaction");
+
+        let command = Shared::new(AddCommand::default());
+        let total = command.total.clone();
+        facade.register_undoable_command(ADD, command);
+
+        let history = facade.history();
+        history.transaction(|| {
+            facade.send(ADD, Some(1));
+            facade.send(ADD, Some(2));
+        });
+        assert_eq!(total.load(Ordering::SeqCst), 3);
+
+        assert!(history.undo());
+        assert_eq!(total.load(Ordering::SeqCst), 0);
+        assert!(!history.can_undo());
+
+        BaseFacade::<u32>::remove_core("undo-transaction");
+    }
+
+    #[test]
+    fn should_limit_history_depth() {
+        let facade = BaseFacade::<u32>::instance("undo-depth");
+
+        let command = Shared::new(AddCommand::default());
+        let total = command.total.clone();
+        facade.register_undoable_command(ADD, command);
+
+        let history = facade.history();
+        history.set_max_depth(2);
+
+        for _ in 0..3 {
+            facade.send(ADD, Some(1));
+        }
+
+        assert!(history.undo());
+        assert!(history.undo());
+        assert!(!history.undo());
+        assert_eq!(total.load(Ordering::SeqCst), 1);
+
+        BaseFacade::<u32>::remove_core("undo-depth");
+    }
+}
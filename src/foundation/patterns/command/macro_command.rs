@@ -1,8 +1,8 @@
-use std::{cell::RefCell, fmt, rc::Rc};
+use std::fmt;
 
 use crate::{
-    foundation::patterns::facade::BaseFacade,
-    prelude::{Command, Facade, Interest, Notification, Notifier, Singleton},
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{Command, CoreKey, Interest, Lock, MaybeSendSync, Notification, Notifier, Shared},
 };
 
 /// A base [Command] implementation that executes other _Commands_.
@@ -15,12 +15,14 @@ use crate::{
 /// Each _SubCommand_ will be passed a reference to the original
 /// [Notification] that was passed to the [MacroCommand]'s
 /// [execute](Command::execute) method.
-
 pub struct MacroCommand<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    sub_commands: RefCell<Vec<Box<dyn Command<Body>>>>,
+    sub_commands: Lock<Vec<Box<dyn Command<Body>>>>,
+
+    // Sends notifications through the Core the command is registered with
+    notifier: BaseNotifier,
 }
 
 impl<Body> MacroCommand<Body>
@@ -31,7 +33,8 @@ where
     ///
     pub fn new() -> Self {
         Self {
-            sub_commands: RefCell::new(Vec::new()),
+            sub_commands: Lock::new(Vec::new()),
+            notifier: BaseNotifier::new(),
         }
     }
 
@@ -51,17 +54,34 @@ where
     }
 }
 
+impl<Body> Default for MacroCommand<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl<Body> Command<Body> for MacroCommand<Body>
 where
     Body: fmt::Debug + 'static,
 {
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
     /// Execute this [MacroCommand]'s `SubCommands`.
     ///
     /// The `SubCommands` will be called in First In/First Out (FIFO)
     /// order.
-    fn execute(&self, notification: Rc<dyn Notification<Body>>) {
+    fn execute(&self, notification: Shared<dyn Notification<Body>>) {
+        let key = self.notifier.key();
         let mut sub_commands = self.sub_commands.borrow_mut();
         while let Some(command) = sub_commands.pop() {
+            if let Some(ref key) = key {
+                command.initialize_notifier(key);
+            }
             command.execute(notification.clone());
         }
     }
@@ -69,11 +89,10 @@ where
 
 impl<Body> Notifier<Body> for MacroCommand<Body>
 where
-    Body: fmt::Debug + 'static,
+    Body: fmt::Debug + MaybeSendSync + 'static,
 {
     fn send(&self, interest: Interest, body: Option<Body>) {
-        log::error!("You should implement yourself MacroCommand");
-        BaseFacade::<Body>::global().send(interest, body);
+        self.notifier.send(interest, body);
     }
 }
 
@@ -32,6 +32,28 @@
 //! to call the addSubCommand method once for each Command to be
 //! added. You may add any combination of SimpleCommands or
 //! MacroCommands.
+//!
+//! ## Use of Async Commands
+//!
+//! Commands which do I/O implement the AsyncCommand trait instead,
+//! so they do not block the notification loop. The Controller hands over
+//! the future of the AsyncCommand to its Spawner, which is implemented
+//! on top of the runtime of your application, or the built-in LocalExecutor.
+//! The Facade send_async method returns a future, which resolves when
+//! every triggered AsyncCommand has finished.
+//!
+//! ## Use of Undoable Commands
+//!
+//! Commands which implement the UndoableCommand trait are recorded by the
+//! CommandHistory of the Controller each time they are executed, so the
+//! changes they have made are able to be undone and redone. Several commands
+//! are able to be grouped into one transaction.
+
+mod executor;
+pub use self::executor::*;
+
+mod history;
+pub use self::history::*;
 
 mod macro_command;
 pub use self::macro_command::*;
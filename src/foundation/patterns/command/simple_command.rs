@@ -1,34 +1,46 @@
-use std::{fmt, rc::Rc};
+use std::fmt;
 
 use crate::{
-    foundation::patterns::facade::BaseFacade,
-    prelude::{Command, Facade, Interest, Notification, Notifier, Singleton},
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{Command, CoreKey, Interest, MaybeSendSync, Notification, Notifier, Shared},
 };
 
 /// A reference [Command] implementation.
-pub struct SimpleCommand {}
+#[derive(Default)]
+pub struct SimpleCommand {
+    // Sends notifications through the Core the command is registered with
+    notifier: BaseNotifier,
+}
 
-impl SimpleCommand {}
+impl SimpleCommand {
+    /// Constructor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
 
 impl<Body> Command<Body> for SimpleCommand
 where
     Body: fmt::Debug + 'static,
 {
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
     /// Fulfill the use-case initiated by the given [Notification].
     ///
     /// In the Command Pattern, an application use-case typically
     /// begins with some user action, which results in an [Notification] being broadcast, which
     /// is handled by business logic in the [execute](Command::execute) method of an [Command].
-    fn execute(&self, _notification: Rc<dyn Notification<Body>>) {}
+    fn execute(&self, _notification: Shared<dyn Notification<Body>>) {}
 }
 
 impl<Body> Notifier<Body> for SimpleCommand
 where
-    Body: fmt::Debug + 'static,
+    Body: fmt::Debug + MaybeSendSync + 'static,
 {
     fn send(&self, interest: Interest, body: Option<Body>) {
-        log::error!("You should implement yourself SimpleCommand");
-        BaseFacade::<Body>::global().send(interest, body);
+        self.notifier.send(interest, body);
     }
 }
 
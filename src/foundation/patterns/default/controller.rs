@@ -1,40 +1,101 @@
-use std::{cell::RefCell, collections::HashMap, fmt, rc::Rc};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    sync::atomic::{AtomicU64, Ordering},
+};
 
-use crate::prelude::*;
+use crate::{
+    foundation::patterns::{
+        command::{join_all, CommandHistory, JoinAll},
+        observer::BaseObserver,
+    },
+    prelude::*,
+};
 
-/// A Singleton [Controller] implementation.
+use super::{BaseView, CommandInfo, CommandKind, Core, CoreActor};
+
+// Controller contexts have the high bit set, so they never clash with Mediator's ids
+const CONTROLLER_CONTEXT: u64 = 1 << 63;
+
+static NEXT_CONTROLLER_CONTEXT: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    // Futures of the AsyncCommand's triggered while joined on this thread
+    static TRIGGERED: RefCell<Vec<Vec<BoxFuture<'static, ()>>>> = RefCell::new(Vec::new());
+}
+
+// Registered Command of either kind
+enum CommandEntry<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    Sync(Shared<dyn Command<Body>>),
+    Async(Shared<dyn AsyncCommand<Body>>),
+    Undoable(Shared<dyn UndoableCommand<Body>>),
+}
+
+impl<Body> Clone for CommandEntry<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        match self {
+            Self::Sync(command) => Self::Sync(command.clone()),
+            Self::Async(command) => Self::Async(command.clone()),
+            Self::Undoable(command) => Self::Undoable(command.clone()),
+        }
+    }
+}
+
+/// A Multiton [Controller] implementation.
 ///
 /// In PureMVC, the [Controller] class follows the
 /// 'Command and Controller' strategy, and assumes these responsibilities:
 ///
 /// - Remembering which [Command]'s are intended to handle which [Notification]'s.
 /// - Registering itself as an [Observer] with the [View] for each [Notification]
-/// that it has an [Command] mapping for.
+///   that it has an [Command] mapping for.
 /// - Creating a new instance of the proper [Command] to handle a given [Notification]
-/// when notified by the [View].
+///   when notified by the [View].
 /// - Calling the [Command]'s [execute] method, passing in the [Notification].
 ///
 ///
 /// Your application must register [Command]'s with the [Controller].
 /// The simplest way is to subclass [Facade],
 /// and use its [register_command] method to add your registrations.
-/// 
+///
+/// The [Controller] holds the [View] of the same Core, so it registers and removes
+/// its [Observer]'s itself.
+///
+/// The futures of the [AsyncCommand]'s are handed over to the [Spawner] of the [Controller],
+/// unless they are joined by [join_triggered](BaseController::join_triggered).
+/// The executions of the [UndoableCommand]'s are recorded by its [CommandHistory].
+///
 /// [execute]: Command::execute
 /// [register_command]: Controller::register_command
-
 pub struct BaseController<Body>
 where
     Body: fmt::Debug + 'static,
 {
+    // The Multiton Key for this Core
+    key: CoreKey,
+
+    // Local reference to View
+    view: Shared<BaseView<Body>>,
+
     // Mapping of Notification names to Command Class references
     // Mayby use IntMap for performance
-    command_map: RefCell<HashMap<Interest, Rc<dyn Command<Body>>>>,
+    command_map: Lock<HashMap<Interest, CommandEntry<Body>>>,
 
-    notify_context: Rc<BaseNotifyContext>,
-}
+    // Executor of the AsyncCommand's
+    spawner: Lock<Option<Shared<dyn Spawner>>>,
 
-unsafe impl<Body> std::marker::Send for BaseController<Body> where Body: fmt::Debug + 'static {}
-unsafe impl<Body> std::marker::Sync for BaseController<Body> where Body: fmt::Debug + 'static {}
+    // Undo history of the UndoableCommand's
+    history: CommandHistory<Body>,
+
+    notify_context: Shared<BaseNotifyContext>,
+}
 
 impl<Body> BaseController<Body>
 where
@@ -42,54 +103,170 @@ where
 {
     /// Create instance of BaseController.
     ///
-    /// Actually, you have to reimplement the [Controller] for your purposes with Singleton pattern.
-    ///
-    /// This [View] implementation is keept here in educational purposes only.
+    /// This [Controller] implementation is a Multiton, so you should not call the constructor
+    /// directly, but instead call the static Multiton Factory method [instance()][1]
     ///
-    pub fn new() -> Self {
+    /// [1]: Multiton::instance
+    pub fn new(key: &CoreKey) -> Self {
         Self {
-            command_map: RefCell::new(HashMap::new()),
-            notify_context: Rc::new(BaseNotifyContext {}),
+            key: key.clone(),
+            view: BaseView::instance(key),
+            command_map: Lock::new(HashMap::new()),
+            spawner: Lock::new(None),
+            history: CommandHistory::new(),
+            notify_context: Shared::new(BaseNotifyContext {
+                id: CONTROLLER_CONTEXT | NEXT_CONTROLLER_CONTEXT.fetch_add(1, Ordering::Relaxed),
+            }),
         }
     }
 
+    /// Retrieve the Multiton Key of the [Controller]
+    pub fn key(&self) -> &CoreKey {
+        &self.key
+    }
+
     /// Reprecent controller as [NotifyContext]
-    pub fn as_context(&self) -> Rc<dyn NotifyContext> {
+    pub fn as_context(&self) -> Shared<dyn NotifyContext> {
         self.notify_context.clone()
     }
+
+    /// Retrieve the undo history of the [UndoableCommand]'s
+    pub fn history(&self) -> &CommandHistory<Body> {
+        &self.history
+    }
+
+    /// Set the [Spawner] which drives the [AsyncCommand]'s
+    pub fn set_spawner(&self, spawner: Shared<dyn Spawner>) {
+        self.spawner.replace(Some(spawner));
+    }
+
+    /// Join the [AsyncCommand]'s triggered while `f` is called on the current thread.
+    ///
+    /// The futures of such [AsyncCommand]'s are not handed over to the [Spawner],
+    /// but driven by the returned future instead.
+    pub fn join_triggered(f: impl FnOnce()) -> JoinAll {
+        TRIGGERED.with(|triggered| triggered.borrow_mut().push(Vec::new()));
+
+        f();
+
+        let futures = TRIGGERED.with(|triggered| triggered.borrow_mut().pop()).unwrap_or_default();
+        join_all(futures)
+    }
+
+    /// Describe the registered commands, in the order of their [Interest]'s
+    pub fn commands(&self) -> Vec<CommandInfo> {
+        let mut commands: Vec<_> = self
+            .command_map
+            .borrow()
+            .iter()
+            .map(|(interest, command)| {
+                let (kind, command) = match command {
+                    CommandEntry::Sync(command) => (CommandKind::Sync, format!("{:?}", command)),
+                    CommandEntry::Async(command) => (CommandKind::Async, format!("{:?}", command)),
+                    CommandEntry::Undoable(command) => (CommandKind::Undoable, format!("{:?}", command)),
+                };
+                CommandInfo {
+                    interest: *interest,
+                    kind,
+                    command,
+                }
+            })
+            .collect();
+        commands.sort_by_key(|command| command.interest);
+        commands
+    }
+
+    /// Retrieve the number of the registered commands
+    pub fn command_count(&self) -> usize {
+        self.command_map.borrow().len()
+    }
+
+    fn spawn(&self, future: BoxFuture<'static, ()>) {
+        let future = TRIGGERED.with(|triggered| match triggered.borrow_mut().last_mut() {
+            Some(futures) => {
+                futures.push(future);
+                None
+            }
+            None => Some(future),
+        });
+
+        if let Some(future) = future {
+            let spawner = self.spawner.borrow().clone();
+            match spawner {
+                Some(spawner) => spawner.spawn(future),
+                None => log::error!("Spawner is not set [BaseController], the AsyncCommand is dropped"),
+            }
+        }
+    }
+
+    fn register_entry(&self, interest: Interest, command: CommandEntry<Body>) {
+        if !self.has_command(&interest) {
+            let key = self.key.clone();
+            self.view.register_observer(
+                interest,
+                Shared::new(BaseObserver::new(
+                    Box::new(move |notification| {
+                        // the observer is owned by the View, so refer the Controller by its key
+                        if let Some(controller) = Core::retrieve::<Self>(&key) {
+                            controller.execute_command(notification);
+                        }
+                        Propagation::Continue
+                    }),
+                    self.as_context(),
+                )),
+            );
+        }
+
+        self.command_map.borrow_mut().insert(interest, command);
+    }
 }
 
-impl<Body> Singleton for BaseController<Body>
+impl<Body> Multiton for BaseController<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    /// [Controller] Singleton Factory method
-    ///
-    /// It is not possible to implement Singleton with generics.
-    /// So you should implement it in your final code only.
-    ///
-    /// Error: use of generic parameter from outer function
+    /// Controller Multiton Factory method
     ///
-    fn global() -> &'static Self {
-        // static BASE_CONTROLLER_INSTANCE: OnceCell<BaseFacade<Body>> = OnceCell::new();
-        // BASE_CONTROLLER_INSTANCE.get_or_init(Self::new)
-        todo!("you have to reimplement the controller for your purposes")
+    fn instance(key: impl Into<CoreKey>) -> Shared<Self> {
+        Core::instance(&key.into(), Self::new)
     }
 }
 
-impl<Body> Controller<Body> for BaseController<Body>
+impl<Body> CoreActor for BaseController<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    fn execute_command(&self, notification: Rc<dyn Notification<Body>>) {
-        let command_map = self.command_map.borrow();
+    fn on_remove_core(&self) {
+        self.command_map.borrow_mut().clear();
+        self.history.clear();
+    }
+}
 
+impl<Body> Controller<Body> for BaseController<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn execute_command(&self, notification: Shared<dyn Notification<Body>>) {
         log::info!("Execute Command [BaseController] {:?}", notification);
 
-        command_map.get(&notification.interest()).map(|command| {
-            log::info!("Command [BaseController] {:?} for {:?}", command, notification);
-            command.execute(notification)
-        });
+        // the command may register or remove commands while executed
+        let command = self.command_map.borrow().get(&notification.interest()).cloned();
+
+        match command {
+            Some(CommandEntry::Sync(command)) => {
+                log::info!("Command [BaseController] {:?} for {:?}", command, notification);
+                command.execute(notification)
+            }
+            Some(CommandEntry::Async(command)) => {
+                log::info!("AsyncCommand [BaseController] {:?} for {:?}", command, notification);
+                self.spawn(command.execute(notification))
+            }
+            Some(CommandEntry::Undoable(command)) => {
+                log::info!("UndoableCommand [BaseController] {:?} for {:?}", command, notification);
+                self.history.execute(command, notification)
+            }
+            None => {}
+        }
     }
 
     fn has_command(&self, interest: &Interest) -> bool {
@@ -98,31 +275,36 @@ where
         command_map.contains_key(interest)
     }
 
-    fn register_command(&self, interest: Interest, command: Rc<dyn Command<Body>>) {
+    fn register_command(&self, interest: Interest, command: Shared<dyn Command<Body>>) {
         log::info!("Register Command [BaseController] {:?}", interest);
-        {
-            // this code should be uncommented in your final version
-            // check the [Facade]::register_command
-            // if !self.has_command(interest) {
-            //     View::instance().register_observer(
-            //         interest,
-            //         Box::new(Observer::new(Box::new(|notification| self.execute_command(notification)), self.as_context())),
-            //     );
-            // }
-        }
 
-        self.command_map.borrow_mut().insert(interest, command);
+        command.initialize_notifier(&self.key);
+
+        self.register_entry(interest, CommandEntry::Sync(command));
+    }
+
+    fn register_async_command(&self, interest: Interest, command: Shared<dyn AsyncCommand<Body>>) {
+        log::info!("Register AsyncCommand [BaseController] {:?}", interest);
+
+        command.initialize_notifier(&self.key);
+
+        self.register_entry(interest, CommandEntry::Async(command));
+    }
+
+    fn register_undoable_command(&self, interest: Interest, command: Shared<dyn UndoableCommand<Body>>) {
+        log::info!("Register UndoableCommand [BaseController] {:?}", interest);
+
+        command.initialize_notifier(&self.key);
+
+        self.register_entry(interest, CommandEntry::Undoable(command));
     }
 
     fn remove_command(&self, interest: &Interest) {
         // if the Command is registered...
         if self.has_command(interest) {
-            {
-                // this code should be uncommented in your final version
-                // check the [Facade]::remove_command
-                // // remove the observer
-                // View::instance().remove_observer(interest, &self.as_context());
-            }
+            // remove the observer
+            self.view.remove_observer(interest, &self.as_context());
+
             self.command_map.borrow_mut().remove(interest);
         }
     }
@@ -140,17 +322,19 @@ where
 }
 
 #[derive(Clone, Copy)]
-struct BaseNotifyContext;
+struct BaseNotifyContext {
+    id: u64,
+}
 
 impl NotifyContext for BaseNotifyContext {
     fn id(&self) -> u64 {
-        0x01
+        self.id
     }
 }
 
-impl NotifyContext for Rc<BaseNotifyContext> {
+impl NotifyContext for Shared<BaseNotifyContext> {
     fn id(&self) -> u64 {
-        0x01
+        self.as_ref().id()
     }
 }
 
@@ -0,0 +1,116 @@
+use std::fmt;
+
+use crate::prelude::{CoreKey, Interest, InterestPattern, Priority};
+
+/// Describes the [Proxy](crate::prelude::Proxy) registered with the [BaseModel](super::BaseModel)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyInfo {
+    /// The name the proxy is registered with, `None` if it is registered by type
+    pub name: Option<String>,
+    /// The type name of the proxy
+    pub type_name: &'static str,
+}
+
+/// Describes the [Mediator](crate::prelude::Mediator) registered with the [BaseView](super::BaseView)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MediatorInfo {
+    /// The name the mediator is registered with, `None` if it is registered by type
+    pub name: Option<String>,
+    /// The type name of the mediator
+    pub type_name: &'static str,
+    /// The interests the mediator is notified of
+    pub interests: Vec<Interest>,
+    /// The priority the mediator is notified with
+    pub priority: Priority,
+}
+
+/// The kind of the command registered with the [BaseController](super::BaseController)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandKind {
+    /// The [Command](crate::prelude::Command)
+    Sync,
+    /// The [AsyncCommand](crate::prelude::AsyncCommand)
+    Async,
+    /// The [UndoableCommand](crate::prelude::UndoableCommand)
+    Undoable,
+}
+
+/// Describes the command registered with the [BaseController](super::BaseController)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandInfo {
+    /// The interest the command is executed for
+    pub interest: Interest,
+    /// The kind of the command
+    pub kind: CommandKind,
+    /// The [Debug] representation of the command, which starts with its type name when derived
+    pub command: String,
+}
+
+/// Describes everything registered with the Core, built by
+/// [describe](crate::foundation::patterns::facade::BaseFacade::describe).
+///
+/// The report is displayed as the readable multiline text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CoreReport {
+    /// The key of the Core
+    pub key: CoreKey,
+    /// The registered proxies
+    pub proxies: Vec<ProxyInfo>,
+    /// The registered mediators
+    pub mediators: Vec<MediatorInfo>,
+    /// The registered commands, in the order of their interests
+    pub commands: Vec<CommandInfo>,
+    /// The number of the observers of each interest, in the order of the interests
+    pub observers: Vec<(Interest, usize)>,
+    /// The number of the wildcard observers of each pattern
+    pub patterns: Vec<(InterestPattern, usize)>,
+}
+
+impl CoreReport {
+    /// Check whether the interest has any observer, either exact or wildcard matching it.
+    ///
+    /// The commands and the mediators are notified by their observers,
+    /// so the interest without observers is not handled at all.
+    pub fn is_handled(&self, interest: Interest) -> bool {
+        let exact = self.observers.iter().any(|(other, count)| *other == interest && *count > 0);
+        exact || self.patterns.iter().any(|(pattern, count)| *count > 0 && pattern.matches(interest))
+    }
+}
+
+impl fmt::Display for CoreReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Core {}", self.key.as_str())?;
+
+        writeln!(f, "Proxies ({}):", self.proxies.len())?;
+        for proxy in &self.proxies {
+            match &proxy.name {
+                Some(name) => writeln!(f, "  {} \"{}\"", proxy.type_name, name)?,
+                None => writeln!(f, "  {}", proxy.type_name)?,
+            }
+        }
+
+        writeln!(f, "Mediators ({}):", self.mediators.len())?;
+        for mediator in &self.mediators {
+            match &mediator.name {
+                Some(name) => write!(f, "  {} \"{}\"", mediator.type_name, name)?,
+                None => write!(f, "  {}", mediator.type_name)?,
+            }
+            writeln!(f, " {:?} priority {}", mediator.interests, mediator.priority)?;
+        }
+
+        writeln!(f, "Commands ({}):", self.commands.len())?;
+        for command in &self.commands {
+            writeln!(f, "  {:?} {:?} {}", command.interest, command.kind, command.command)?;
+        }
+
+        writeln!(f, "Observers:")?;
+        for (interest, count) in &self.observers {
+            writeln!(f, "  {:?} {}", interest, count)?;
+        }
+        for (pattern, count) in &self.patterns {
+            writeln!(f, "  {:?} {}", pattern, count)?;
+        }
+
+        Ok(())
+    }
+}
@@ -5,20 +5,23 @@
 //! [Model][2], [View][3] and [Controller][1].
 //! 
 //! In this implementation of the classic MVC design meta-pattern, the
-//! application tiers are represented by three Singletons (a class where
-//! only one instance may be created).
+//! application tiers are represented by three Multitons (a class where
+//! only one instance may be created per [Core] key).
 //! 
-//! A fourth Singleton, the Facade, simplifies development by providing a
+//! A fourth Multiton, the Facade, simplifies development by providing a
 //! single interface for communications throughout the application.
 //! 
+//! Every Core is identified by its [CoreKey][7], so several independent Cores
+//! may be running side by side, and removed with all their actors at once.
+//! 
 //! - The [Model][2] caches named references to [Proxies][6], which expose an API for
-//! manipulating the Data Model (including data retrieved from remote services).
+//!   manipulating the Data Model (including data retrieved from remote services).
 //! - The [View][3] primarily caches named references to [Mediator][4]'s, which adapt and
-//! steward the [View][3] Components that make up the user interface.
+//!   steward the [View][3] Components that make up the user interface.
 //! - The [Controller][1] maintains named mappings to [Command][5] classes, which are
-//! stateless, and only created when needed.
+//!   stateless, and only created when needed.
 //! - The Facade initializes and caches the Core actors ([Model][2], [View][3] and
-//! [Controller][1]), and provides a single place to access all of their public methods.
+//!   [Controller][1]), and provides a single place to access all of their public methods.
 //!
 //! [1]: crate::prelude::Controller
 //! [2]: crate::prelude::Model
@@ -26,10 +29,17 @@
 //! [4]: crate::prelude::Mediator
 //! [5]: crate::prelude::Command
 //! [6]: crate::prelude::Proxy
+//! [7]: crate::prelude::CoreKey
 //! 
 mod controller;
 pub use self::controller::*;
 
+mod introspection;
+pub use self::introspection::*;
+
+mod multiton;
+pub use self::multiton::*;
+
 mod model;
 pub use self::model::*;
 
@@ -1,14 +1,87 @@
-use once_cell::sync::OnceCell;
-use std::{
-    any::{Any, TypeId},
-    cell::RefCell,
-    collections::BTreeMap,
-    rc::Rc,
-};
+use std::{any::TypeId, collections::BTreeMap, error::Error, fmt};
 
-use crate::prelude::{Model, Proxy, Singleton};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
-/// A Singleton [Model] implementation.
+use crate::prelude::{CoreKey, Lock, MaybeSendSync, Model, Multiton, Proxy, Shared, SharedAny, SnapshotProxy};
+
+use super::{Core, CoreActor, ProxyInfo};
+
+// Type erased [Proxy] which is still able to be notified on removal
+trait ProxyEntry: MaybeSendSync {
+    fn type_name(&self) -> &'static str;
+
+    fn on_remove(&self);
+
+    fn into_any(self: Shared<Self>) -> SharedAny;
+}
+
+impl<P: Proxy> ProxyEntry for P {
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<P>()
+    }
+
+    fn on_remove(&self) {
+        Proxy::on_remove(self)
+    }
+
+    fn into_any(self: Shared<Self>) -> SharedAny {
+        self
+    }
+}
+
+// Puts the deserialized data into the [SnapshotProxy]
+type Restore = Box<dyn FnOnce()>;
+
+// Type erased [SnapshotProxy]
+trait SnapshotEntry: MaybeSendSync {
+    fn proxy_type(&self) -> TypeId;
+
+    fn snapshot(&self) -> Result<Value, serde_json::Error>;
+
+    fn prepare(self: Shared<Self>, data: &Value) -> Result<Restore, serde_json::Error>;
+}
+
+impl<P: SnapshotProxy> SnapshotEntry for P {
+    fn proxy_type(&self) -> TypeId {
+        TypeId::of::<P>()
+    }
+
+    fn snapshot(&self) -> Result<Value, serde_json::Error> {
+        serde_json::to_value(SnapshotProxy::snapshot(self))
+    }
+
+    fn prepare(self: Shared<Self>, data: &Value) -> Result<Restore, serde_json::Error> {
+        let snapshot = P::Snapshot::deserialize(data)?;
+        Ok(Box::new(move || SnapshotProxy::restore(&*self, snapshot)))
+    }
+}
+
+/// The serializable snapshot of the [SnapshotProxy]'s registered with the [BaseModel]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ModelSnapshot {
+    /// The data captured from the proxies, by the [name](SnapshotProxy::NAME) of the proxy
+    pub proxies: BTreeMap<String, Value>,
+}
+
+/// Represents the failure to register the [SnapshotProxy]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SnapshotError {
+    /// The [SnapshotProxy] of another type is registered with the name
+    NameTaken(&'static str),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NameTaken(name) => write!(f, "The snapshot name {} is already taken by another Proxy", name),
+        }
+    }
+}
+
+impl Error for SnapshotError {}
+
+/// A Multiton [Model] implementation.
 ///
 /// In PureMVC, the [Model] class provides access to model objects (Proxies) by named lookup.
 ///
@@ -21,37 +94,148 @@ use crate::prelude::{Model, Proxy, Singleton};
 /// Your application must register [Proxy] instances with the [Model]. Typically, you use an
 /// [Command] to create and register [Proxy] instances once the [Facade] has initialized the Core
 /// actors.
-/// 
+///
 /// [Command]: crate::prelude::Command
 /// [Facade]: crate::prelude::Facade
 pub struct BaseModel {
+    // The Multiton Key for this Core
+    key: CoreKey,
+
     // Mapping of proxy types to [Proxy] instances
-    storages: RefCell<BTreeMap<TypeId, Rc<dyn Any>>>,
-}
+    storages: Lock<BTreeMap<TypeId, Shared<dyn ProxyEntry>>>,
 
-unsafe impl std::marker::Send for BaseModel {}
-unsafe impl std::marker::Sync for BaseModel {}
+    // Mapping of names to [Proxy] instances
+    named_storages: Lock<BTreeMap<String, Shared<dyn ProxyEntry>>>,
+
+    // Mapping of names to the proxies captured into the snapshot
+    snapshots: Lock<BTreeMap<&'static str, Shared<dyn SnapshotEntry>>>,
+}
 
 impl BaseModel {
     /// Create instance of BaseModel.
     ///
-    /// This [Model] implementation is a Singleton, so you should not call the constructor
-    /// directly, but instead call the static Singleton Factory method [global()][1]
-    /// 
-    /// [1]: Singleton::global
-    pub fn new() -> Self {
+    /// This [Model] implementation is a Multiton, so you should not call the constructor
+    /// directly, but instead call the static Multiton Factory method [instance()][1]
+    ///
+    /// [1]: Multiton::instance
+    pub fn new(key: &CoreKey) -> Self {
         Self {
-            storages: RefCell::new(BTreeMap::new()),
+            key: key.clone(),
+            storages: Lock::new(BTreeMap::new()),
+            named_storages: Lock::new(BTreeMap::new()),
+            snapshots: Lock::new(BTreeMap::new()),
         }
     }
+
+    /// Retrieve the Multiton Key of the [Model]
+    pub fn key(&self) -> &CoreKey {
+        &self.key
+    }
+
+    /// Register the [SnapshotProxy], which is captured into the [snapshot](BaseModel::snapshot).
+    ///
+    /// The [SnapshotProxy] of another type registered with the same name is left intact,
+    /// and [NameTaken](SnapshotError::NameTaken) is returned, as one of them would be
+    /// missing from the snapshots.
+    pub fn register_snapshot_proxy<P: SnapshotProxy>(&self, proxy: Shared<P>) -> Result<(), SnapshotError> {
+        let taken = matches!(
+            self.snapshots.borrow().get(P::NAME),
+            Some(entry) if entry.proxy_type() != TypeId::of::<P>()
+        );
+        if taken {
+            return Err(SnapshotError::NameTaken(P::NAME));
+        }
+
+        self.register_proxy(proxy.clone());
+        self.snapshots.borrow_mut().insert(P::NAME, proxy);
+        Ok(())
+    }
+
+    /// Capture the data of the registered [SnapshotProxy]'s
+    pub fn snapshot(&self) -> Result<ModelSnapshot, serde_json::Error> {
+        // the proxies are not borrowed while captured, so they are able to access the Model
+        let entries = self.snapshot_entries();
+
+        let mut snapshot = ModelSnapshot::default();
+        for (name, proxy) in entries {
+            snapshot.proxies.insert(name.into(), proxy.snapshot()?);
+        }
+        Ok(snapshot)
+    }
+
+    /// Put the data of the snapshot back into the registered [SnapshotProxy]'s.
+    ///
+    /// The data of all proxies is deserialized first, so if any of it is invalid
+    /// none of the proxies is restored. The proxies missing from the snapshot are left intact,
+    /// the data of the proxies which are not registered is skipped.
+    pub fn restore(&self, snapshot: &ModelSnapshot) -> Result<(), serde_json::Error> {
+        let entries = self.snapshot_entries();
+
+        for name in snapshot.proxies.keys() {
+            if !entries.iter().any(|(entry, _)| entry == name) {
+                log::warn!("Skip unknown Proxy of snapshot [BaseModel] {}", name);
+            }
+        }
+
+        let restores = entries
+            .into_iter()
+            .filter_map(|(name, proxy)| snapshot.proxies.get(name).map(|data| proxy.prepare(data)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        for restore in restores {
+            restore();
+        }
+        Ok(())
+    }
+
+    /// Describe the registered [Proxy]'s, the ones registered by type go first
+    pub fn proxies(&self) -> Vec<ProxyInfo> {
+        let storages = self.storages.borrow();
+        let named_storages = self.named_storages.borrow();
+
+        let typed = storages.values().map(|proxy| ProxyInfo {
+            name: None,
+            type_name: proxy.type_name(),
+        });
+        let named = named_storages.iter().map(|(name, proxy)| ProxyInfo {
+            name: Some(name.clone()),
+            type_name: proxy.type_name(),
+        });
+
+        typed.chain(named).collect()
+    }
+
+    /// Retrieve the number of the registered [Proxy]'s
+    pub fn proxy_count(&self) -> usize {
+        self.storages.borrow().len() + self.named_storages.borrow().len()
+    }
+
+    fn snapshot_entries(&self) -> Vec<(&'static str, Shared<dyn SnapshotEntry>)> {
+        self.snapshots
+            .borrow()
+            .iter()
+            .map(|(name, proxy)| (*name, proxy.clone()))
+            .collect()
+    }
 }
 
-impl Singleton for BaseModel {
-    /// Model Singleton Factory method
+impl Multiton for BaseModel {
+    /// Model Multiton Factory method
     ///
-    fn global() -> &'static Self {
-        static BASE_MODEL_INSTANCE: OnceCell<BaseModel> = OnceCell::new();
-        BASE_MODEL_INSTANCE.get_or_init(Self::new)
+    fn instance(key: impl Into<CoreKey>) -> Shared<Self> {
+        Core::instance(&key.into(), Self::new)
+    }
+}
+
+impl CoreActor for BaseModel {
+    fn on_remove_core(&self) {
+        let proxies = std::mem::take(&mut *self.storages.borrow_mut());
+        let named_proxies = std::mem::take(&mut *self.named_storages.borrow_mut());
+        self.snapshots.borrow_mut().clear();
+
+        for proxy in proxies.values().chain(named_proxies.values()) {
+            proxy.on_remove();
+        }
     }
 }
 
@@ -61,40 +245,45 @@ impl Model for BaseModel {
         self.storages.borrow().contains_key(&type_id)
     }
 
-    fn register_proxy<P: Proxy>(&self, proxy: Rc<P>) {
+    fn register_proxy<P: Proxy>(&self, proxy: Shared<P>) {
         let type_id = TypeId::of::<P>();
 
         log::info!("Register Proxy [BaseModel] {:?}", proxy);
 
+        // the proxy replacing the snapshot one is captured only if registered for the snapshot
+        self.snapshots.borrow_mut().retain(|_, entry| entry.proxy_type() != type_id);
+
+        proxy.initialize_notifier(&self.key);
+
         self.storages.borrow_mut().insert(type_id, proxy.clone());
 
         proxy.on_register();
     }
 
-    fn remove_proxy<P: Proxy>(&self) -> Option<Rc<P>> {
+    fn remove_proxy<P: Proxy>(&self) -> Option<Shared<P>> {
         let type_id = TypeId::of::<P>();
 
-        self.storages
-            .borrow_mut()
-            .remove(&type_id)
-            .map(|proxy| match proxy.downcast::<P>() {
-                Ok(proxy) => {
-                    proxy.on_remove();
-                    proxy
-                }
-                Err(_) => {
-                    panic!("Something wrong with proxy storage");
-                }
-            })
+        let proxy = self.storages.borrow_mut().remove(&type_id);
+        self.snapshots.borrow_mut().retain(|_, entry| entry.proxy_type() != type_id);
+
+        proxy.map(|proxy| match proxy.into_any().downcast::<P>() {
+            Ok(proxy) => {
+                proxy.on_remove();
+                proxy
+            }
+            Err(_) => {
+                panic!("Something wrong with proxy storage");
+            }
+        })
     }
 
-    fn retrieve_proxy<P: Proxy>(&self) -> Option<Rc<P>> {
+    fn retrieve_proxy<P: Proxy>(&self) -> Option<Shared<P>> {
         // log::info!("Retrieve Proxy [BaseModel]");
 
         let type_id = TypeId::of::<P>();
 
         match self.storages.borrow().get(&type_id) {
-            Some(item) => match item.clone().downcast::<P>() {
+            Some(item) => match item.clone().into_any().downcast::<P>() {
                 Ok(proxy) => Some(proxy),
                 Err(_) => {
                     log::error!("Something wrong with proxy storage");
@@ -104,4 +293,214 @@ impl Model for BaseModel {
             None => None,
         }
     }
+
+    fn register_named_proxy<P: Proxy>(&self, name: &str, proxy: Shared<P>) {
+        log::info!("Register Proxy [BaseModel] {} {:?}", name, proxy);
+
+        // the replaced proxy is removed first
+        let previous = self.named_storages.borrow_mut().remove(name);
+        if let Some(previous) = previous {
+            previous.on_remove();
+        }
+
+        proxy.initialize_notifier(&self.key);
+
+        self.named_storages.borrow_mut().insert(name.into(), proxy.clone());
+
+        proxy.on_register();
+    }
+
+    fn retrieve_named_proxy<P: Proxy>(&self, name: &str) -> Option<Shared<P>> {
+        let proxy = self.named_storages.borrow().get(name).cloned()?;
+        proxy.into_any().downcast::<P>().ok()
+    }
+
+    fn remove_named_proxy<P: Proxy>(&self, name: &str) -> Option<Shared<P>> {
+        let proxy = self.retrieve_named_proxy::<P>(name)?;
+
+        self.named_storages.borrow_mut().remove(name);
+        proxy.on_remove();
+        Some(proxy)
+    }
+
+    fn has_named_proxy(&self, name: &str) -> bool {
+        self.named_storages.borrow().contains_key(name)
+    }
+
+    fn proxy_names(&self) -> Vec<String> {
+        self.named_storages.borrow().keys().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use crate::{
+        foundation::patterns::{facade::BaseFacade, proxy::BaseProxy},
+        prelude::*,
+    };
+
+    use super::{BaseModel, ModelSnapshot, SnapshotError};
+
+    #[derive(Debug, Default)]
+    struct CounterProxy {
+        count: Lock<u32>,
+        removed: Lock<bool>,
+    }
+
+    impl Proxy for CounterProxy {
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {
+            *self.removed.borrow_mut() = true;
+        }
+    }
+
+    impl SnapshotProxy for CounterProxy {
+        const NAME: &'static str = "counter";
+
+        type Snapshot = u32;
+
+        fn snapshot(&self) -> u32 {
+            *self.count.borrow()
+        }
+
+        fn restore(&self, snapshot: u32) {
+            *self.count.borrow_mut() = snapshot;
+        }
+    }
+
+    // Takes the name of the counter in the snapshot
+    #[derive(Debug, Default)]
+    struct OtherCounterProxy {
+        count: Lock<u32>,
+    }
+
+    impl Proxy for OtherCounterProxy {
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {}
+    }
+
+    impl SnapshotProxy for OtherCounterProxy {
+        const NAME: &'static str = "counter";
+
+        type Snapshot = u32;
+
+        fn snapshot(&self) -> u32 {
+            *self.count.borrow()
+        }
+
+        fn restore(&self, snapshot: u32) {
+            *self.count.borrow_mut() = snapshot;
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct LabelProxy {
+        label: Lock<String>,
+    }
+
+    impl Proxy for LabelProxy {
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {}
+    }
+
+    impl SnapshotProxy for LabelProxy {
+        const NAME: &'static str = "label";
+
+        type Snapshot = String;
+
+        fn snapshot(&self) -> String {
+            self.label.borrow().clone()
+        }
+
+        fn restore(&self, snapshot: String) {
+            *self.label.borrow_mut() = snapshot;
+        }
+    }
+
+    #[test]
+    fn should_restore_proxies_from_snapshot() -> Result<(), serde_json::Error> {
+        let model = BaseModel::instance("model-snapshot");
+
+        model.register_snapshot_proxy(Shared::new(CounterProxy::default())).unwrap();
+        model.register_proxy(Shared::new(BaseProxy::<u32>::new(Some(1))));
+
+        let proxy = model.retrieve_proxy::<CounterProxy>().unwrap();
+        *proxy.count.borrow_mut() = 3;
+
+        // the proxies which have not opted in are not captured
+        let document = serde_json::to_string(&model.snapshot()?)?;
+        assert_eq!(serde_json::from_str::<Value>(&document)?, json!({ "proxies": { "counter": 3 } }));
+
+        *proxy.count.borrow_mut() = 5;
+        model.restore(&serde_json::from_str::<ModelSnapshot>(&document)?)?;
+        assert_eq!(*proxy.count.borrow(), 3);
+
+        // the invalid data of any proxy prevents restoring all of them
+        model.register_snapshot_proxy(Shared::new(LabelProxy::default())).unwrap();
+        let label = model.retrieve_proxy::<LabelProxy>().unwrap();
+        let invalid = json!({ "proxies": { "counter": 7, "label": 1 } });
+        assert!(model.restore(&serde_json::from_value(invalid)?).is_err());
+        assert_eq!(*proxy.count.borrow(), 3);
+        assert_eq!(*label.label.borrow(), "");
+
+        // the removed proxy is not captured anymore
+        model.remove_proxy::<LabelProxy>();
+        model.remove_proxy::<CounterProxy>();
+        assert!(model.snapshot()?.proxies.is_empty());
+
+        BaseFacade::<()>::remove_core("model-snapshot");
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_snapshot_name_of_another_proxy() {
+        let model = BaseModel::instance("model-snapshot-clash");
+
+        assert_eq!(model.register_snapshot_proxy(Shared::new(CounterProxy::default())), Ok(()));
+        assert_eq!(
+            model.register_snapshot_proxy(Shared::new(OtherCounterProxy::default())),
+            Err(SnapshotError::NameTaken("counter"))
+        );
+
+        // the model is left unchanged
+        assert!(model.has_proxy::<CounterProxy>());
+        assert!(!model.has_proxy::<OtherCounterProxy>());
+        assert_eq!(model.snapshot().unwrap().proxies.len(), 1);
+
+        BaseFacade::<()>::remove_core("model-snapshot-clash");
+    }
+
+    #[test]
+    fn should_register_proxies_by_name() {
+        let model = BaseModel::instance("named-proxies");
+
+        model.register_named_proxy("users", Shared::new(BaseProxy::<u32>::new(Some(1))));
+        model.register_named_proxy("groups", Shared::new(BaseProxy::<u32>::new(Some(2))));
+
+        assert_eq!(model.proxy_names(), vec!["groups".to_string(), "users".to_string()]);
+        assert_eq!(model.retrieve_named_proxy::<BaseProxy<u32>>("users").unwrap().data, Some(1));
+        assert!(!model.has_proxy::<BaseProxy<u32>>());
+
+        // the proxy of another type is neither retrieved nor removed
+        assert!(model.retrieve_named_proxy::<CounterProxy>("groups").is_none());
+        assert!(model.remove_named_proxy::<CounterProxy>("groups").is_none());
+        assert!(model.has_named_proxy("groups"));
+
+        assert_eq!(model.remove_named_proxy::<BaseProxy<u32>>("groups").unwrap().data, Some(2));
+        assert_eq!(model.proxy_names(), vec!["users".to_string()]);
+
+        // the replaced proxy is removed
+        let counter = Shared::new(CounterProxy::default());
+        model.register_named_proxy("users", counter.clone());
+        assert!(model.retrieve_named_proxy::<CounterProxy>("users").is_some());
+        model.register_named_proxy("users", Shared::new(BaseProxy::<u32>::new(None)));
+        assert!(*counter.removed.borrow());
+
+        BaseFacade::<()>::remove_core("named-proxies");
+    }
 }
@@ -0,0 +1,237 @@
+use std::{
+    any::{Any, TypeId},
+    collections::{BTreeMap, HashMap},
+};
+
+use crate::prelude::{CoreKey, MaybeSendSync, Shared, SharedAny};
+
+// Mapping of Core keys to the Core actors instances
+type Cores = HashMap<CoreKey, BTreeMap<TypeId, CoreEntry>>;
+
+#[cfg(not(feature = "sync"))]
+fn with_cores<R>(f: impl FnOnce(&mut Cores) -> R) -> R {
+    use std::cell::RefCell;
+
+    thread_local! {
+        static CORES: RefCell<Cores> = RefCell::new(HashMap::new());
+    }
+
+    CORES.with(|cores| f(&mut cores.borrow_mut()))
+}
+
+#[cfg(feature = "sync")]
+fn with_cores<R>(f: impl FnOnce(&mut Cores) -> R) -> R {
+    use once_cell::sync::OnceCell;
+    use std::sync::{Mutex, PoisonError};
+
+    static CORES: OnceCell<Mutex<Cores>> = OnceCell::new();
+
+    let mut cores = CORES
+        .get_or_init(|| Mutex::new(HashMap::new()))
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    f(&mut cores)
+}
+
+/// Defines the Core actor functionality.
+///
+/// The [Model], [View], [Controller] and [Facade] are the Core actors,
+/// each of them is created once per [CoreKey] and kept by the [Core] registry.
+///
+/// [Model]: crate::prelude::Model
+/// [View]: crate::prelude::View
+/// [Controller]: crate::prelude::Controller
+/// [Facade]: crate::prelude::Facade
+pub trait CoreActor: Any + MaybeSendSync {
+    /// Called by the [Core] registry when the Core is removed.
+    ///
+    /// The actor should release everything registered with it,
+    /// calling `on_remove` on every [Proxy] and [Mediator].
+    ///
+    /// [Proxy]: crate::prelude::Proxy
+    /// [Mediator]: crate::prelude::Mediator
+    fn on_remove_core(&self);
+}
+
+struct CoreEntry {
+    instance: SharedAny,
+    actor: Shared<dyn CoreActor>,
+}
+
+/// The multiton registry of Core actors.
+///
+/// Instead of process-wide Singletons, every Core actor is keyed by a [CoreKey],
+/// so several independent Cores (per plugin, per test, per document) can live
+/// side by side.
+///
+/// By default the Cores are kept per thread, as the Core actors are not thread safe.
+/// With the `sync` feature enabled the Cores are shared by the whole process.
+pub struct Core;
+
+impl Core {
+    /// Retrieve the Core actor registered with the given key,
+    /// the actor is created with `factory` on first access.
+    pub fn instance<T: CoreActor>(key: &CoreKey, factory: impl FnOnce(&CoreKey) -> T) -> Shared<T> {
+        if let Some(actor) = Self::retrieve::<T>(key) {
+            return actor;
+        }
+
+        // the registry is not borrowed here, so the factory
+        // is able to retrieve other actors of the same Core
+        let actor = Shared::new(factory(key));
+
+        with_cores(|cores| {
+            let entry = cores
+                .entry(key.clone())
+                .or_default()
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| CoreEntry {
+                    instance: actor.clone(),
+                    actor: actor.clone(),
+                });
+
+            // the actor could be created by other thread meanwhile
+            entry.instance.clone().downcast::<T>().unwrap_or(actor)
+        })
+    }
+
+    /// Retrieve the Core actor registered with the given key, if any.
+    pub fn retrieve<T: CoreActor>(key: &CoreKey) -> Option<Shared<T>> {
+        with_cores(|cores| {
+            cores
+                .get(key)
+                .and_then(|actors| actors.get(&TypeId::of::<T>()))
+                .and_then(|entry| entry.instance.clone().downcast::<T>().ok())
+        })
+    }
+
+    /// Check if a Core is registered with the given key.
+    pub fn has_core(key: &CoreKey) -> bool {
+        with_cores(|cores| cores.contains_key(key))
+    }
+
+    /// Retrieve the keys of all registered Cores.
+    pub fn keys() -> Vec<CoreKey> {
+        with_cores(|cores| cores.keys().cloned().collect())
+    }
+
+    /// Remove the Core with the given key.
+    ///
+    /// Every actor of the Core is torn down, so each registered [Proxy]
+    /// and [Mediator] receives its `on_remove` call.
+    ///
+    /// [Proxy]: crate::prelude::Proxy
+    /// [Mediator]: crate::prelude::Mediator
+    pub fn remove_core(key: &CoreKey) {
+        // Copy actors to the working array, since the actors
+        // may access the registry while they are torn down
+        let actors = with_cores(|cores| {
+            cores
+                .get(key)
+                .map(|actors| actors.values().map(|entry| entry.actor.clone()).collect::<Vec<_>>())
+        });
+
+        if let Some(actors) = actors {
+            log::info!("Remove Core [Core] {}", key);
+
+            for actor in actors.iter() {
+                actor.on_remove_core();
+            }
+
+            with_cores(|cores| cores.remove(key));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::{
+        foundation::patterns::{facade::BaseFacade, proxy::BaseProxy},
+        prelude::*,
+    };
+
+    use super::Core;
+
+    #[derive(Debug, Default)]
+    struct CounterProxy {
+        removed: Shared<AtomicBool>,
+    }
+
+    impl Proxy for CounterProxy {
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {
+            self.removed.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct CounterMediator {
+        removed: Shared<AtomicBool>,
+    }
+
+    impl NotifyContext for CounterMediator {
+        fn id(&self) -> u64 {
+            0x10
+        }
+    }
+
+    impl Mediator<u32> for CounterMediator {
+        fn view_component(&self) -> Option<Shared<dyn View<u32>>> {
+            None
+        }
+
+        fn set_view_component(&mut self, _component: Option<Shared<dyn View<u32>>>) {}
+
+        fn list_notification_interests(&self) -> &[Interest] {
+            &[Interest(1)]
+        }
+
+        fn handle_notification(&self, _notification: Shared<dyn Notification<u32>>) {}
+
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {
+            self.removed.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn should_keep_cores_independent() {
+        let first = BaseFacade::<u32>::instance("independent-first");
+        let second = BaseFacade::<u32>::instance("independent-second");
+
+        first.register_proxy(Shared::new(BaseProxy::new(Some(1u32))));
+
+        assert!(first.has_proxy::<BaseProxy<u32>>());
+        assert!(!second.has_proxy::<BaseProxy<u32>>());
+        assert!(Shared::ptr_eq(&first, &BaseFacade::<u32>::instance("independent-first")));
+
+        BaseFacade::<u32>::remove_core("independent-first");
+        BaseFacade::<u32>::remove_core("independent-second");
+    }
+
+    #[test]
+    fn should_tear_down_core_on_remove() {
+        let facade = BaseFacade::<u32>::instance("tear-down");
+
+        let proxy = Shared::new(CounterProxy::default());
+        let mediator = Shared::new(CounterMediator::default());
+        let proxy_removed = proxy.removed.clone();
+        let mediator_removed = mediator.removed.clone();
+
+        facade.register_proxy(proxy);
+        facade.register_mediator(mediator);
+        assert!(BaseFacade::<u32>::has_core("tear-down"));
+
+        BaseFacade::<u32>::remove_core("tear-down");
+
+        assert!(proxy_removed.load(Ordering::SeqCst));
+        assert!(mediator_removed.load(Ordering::SeqCst));
+        assert!(!Core::has_core(&CoreKey::from("tear-down")));
+        assert!(!BaseFacade::<u32>::instance("tear-down").has_proxy::<CounterProxy>());
+    }
+}
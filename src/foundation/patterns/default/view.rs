@@ -1,17 +1,174 @@
 use std::{
-    any::{Any, TypeId},
-    cell::RefCell,
+    any::TypeId,
+    cmp::Reverse,
     collections::{BTreeMap, HashMap},
     fmt,
-    rc::Rc,
+    sync::atomic::{AtomicU64, Ordering},
 };
 
 use crate::{
     foundation::patterns::observer::BaseObserver,
-    prelude::{Interest, Mediator, MediatorRegistry, Notification, NotifyContext, Observer, Singleton, View},
+    prelude::{
+        CoreKey, Interest, InterestPattern, Lock, MaybeSendSync, Mediator, MediatorRegistry, Multiton, Notification,
+        NotifyContext, Observer, PatternRegistry, Priority, Propagation, Shared, SharedAny, View, DEFAULT_PRIORITY,
+    },
 };
 
-/// A Singleton [View] implementation.
+use super::{Core, CoreActor, MediatorInfo};
+
+// Observer with the priority and the order it is registered with
+struct ObserverEntry<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    priority: Priority,
+    order: u64,
+    observer: Shared<dyn Observer<Body>>,
+}
+
+impl<Body> Clone for ObserverEntry<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            priority: self.priority,
+            order: self.order,
+            observer: self.observer.clone(),
+        }
+    }
+}
+
+// The list of Observers for a Notification, ordered by priority
+type ObserverList<Body> = Vec<ObserverEntry<Body>>;
+
+// Keep the registration order within the same priority
+fn insert_observer<Body>(observers: &mut ObserverList<Body>, entry: ObserverEntry<Body>)
+where
+    Body: fmt::Debug + 'static,
+{
+    let idx = observers.partition_point(|other| other.priority >= entry.priority);
+    observers.insert(idx, entry);
+}
+
+// There can only be one Observer for a given notify_context
+// in any given Observer list, so remove it
+fn remove_observer<Body>(observers: &mut ObserverList<Body>, context: &Shared<dyn NotifyContext>)
+where
+    Body: fmt::Debug + 'static,
+{
+    if let Some(idx) = observers.iter().position(|entry| entry.observer.compare_context(context)) {
+        observers.remove(idx);
+    }
+}
+
+// Wildcard Observers, indexed by the kind of their patterns
+struct PatternObservers<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    all: ObserverList<Body>,
+    categories: HashMap<u16, ObserverList<Body>>,
+
+    // Ranges and masks are matched one by one
+    others: Vec<(InterestPattern, ObserverEntry<Body>)>,
+}
+
+impl<Body> PatternObservers<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn new() -> Self {
+        Self {
+            all: Vec::new(),
+            categories: HashMap::new(),
+            others: Vec::new(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.all.is_empty() && self.categories.is_empty() && self.others.is_empty()
+    }
+
+    // Append the Observers matching the interest
+    fn collect(&self, interest: Interest, observers: &mut ObserverList<Body>) {
+        observers.extend(self.all.iter().cloned());
+
+        if let Some(category) = self.categories.get(&interest.category()) {
+            observers.extend(category.iter().cloned());
+        }
+
+        observers.extend(
+            self.others
+                .iter()
+                .filter(|(pattern, _)| pattern.matches(interest))
+                .map(|(_, entry)| entry.clone()),
+        );
+    }
+}
+
+// Named Mediator contexts have the second high bit set, so they never clash
+// with Mediator's ids nor with the Controller contexts
+const NAMED_MEDIATOR_CONTEXT: u64 = 1 << 62;
+
+static NEXT_NAMED_MEDIATOR_CONTEXT: AtomicU64 = AtomicU64::new(0);
+
+// The context of the Observers of the named Mediator, unique for each registration,
+// so the instances of the same Mediator type are removed separately
+#[derive(Debug)]
+struct NamedContext(u64);
+
+impl NotifyContext for NamedContext {
+    fn id(&self) -> u64 {
+        self.0
+    }
+}
+
+// The named Mediator with the context of its Observers
+struct NamedMediator<Body> {
+    mediator: Shared<dyn MediatorEntry<Body>>,
+    context: Shared<dyn NotifyContext>,
+}
+
+// Type erased [Mediator] which is still able to be notified on removal
+trait MediatorEntry<Body>: MaybeSendSync {
+    fn describe(&self, name: Option<String>) -> MediatorInfo;
+
+    fn notification_interests(&self) -> Vec<Interest>;
+
+    fn on_remove(&self);
+
+    fn into_any(self: Shared<Self>) -> SharedAny;
+}
+
+impl<Body, M> MediatorEntry<Body> for M
+where
+    Body: fmt::Debug + 'static,
+    M: Mediator<Body>,
+{
+    fn describe(&self, name: Option<String>) -> MediatorInfo {
+        MediatorInfo {
+            name,
+            type_name: std::any::type_name::<M>(),
+            interests: self.list_notification_interests().to_vec(),
+            priority: self.notification_priority(),
+        }
+    }
+
+    fn notification_interests(&self) -> Vec<Interest> {
+        self.list_notification_interests().to_vec()
+    }
+
+    fn on_remove(&self) {
+        Mediator::on_remove(self)
+    }
+
+    fn into_any(self: Shared<Self>) -> SharedAny {
+        self
+    }
+}
+
+/// A Multiton [View] implementation.
 ///
 /// In PureMVC, the [View] class assumes these responsibilities:
 ///
@@ -22,20 +179,30 @@ use crate::{
 /// - Providing a method for broadcasting an [Notification].
 /// - Notifying the [Observer]'s of a given [Notification] when it broadcast.
 ///
-
+/// The [Observer]'s are notified in the order of their priorities, any of them
+/// is able to stop the propagation of the [Notification].
 pub struct BaseView<Body>
 where
     Body: fmt::Debug + 'static,
 {
+    // The Multiton Key for this Core
+    key: CoreKey,
+
     // Mapping of Mediator types to Mediator instances
-    mediator_map: RefCell<BTreeMap<TypeId, Rc<dyn Any>>>,
+    mediator_map: Lock<BTreeMap<TypeId, Shared<dyn MediatorEntry<Body>>>>,
+
+    // Mapping of names to Mediator instances
+    named_mediators: Lock<BTreeMap<String, NamedMediator<Body>>>,
 
     // Mapping of Notification names to Observer lists
-    observer_map: RefCell<HashMap<Interest, Vec<Rc<dyn Observer<Body>>>>>,
-}
+    observer_map: Lock<HashMap<Interest, ObserverList<Body>>>,
 
-unsafe impl<Body> std::marker::Send for BaseView<Body> where Body: fmt::Debug + 'static {}
-unsafe impl<Body> std::marker::Sync for BaseView<Body> where Body: fmt::Debug + 'static {}
+    // Wildcard Observers
+    pattern_observers: Lock<PatternObservers<Body>>,
+
+    // Registration order of the Observers
+    next_order: AtomicU64,
+}
 
 impl<Body> BaseView<Body>
 where
@@ -43,33 +210,176 @@ where
 {
     /// Create instance of BaseView.
     ///
-    /// Actually, you have to reimplement the [View] for your purposes with Singleton pattern.
+    /// This [View] implementation is a Multiton, so you should not call the constructor
+    /// directly, but instead call the static Multiton Factory method [instance()][1]
     ///
-    /// This [View] implementation is keept here in educational purposes only.
-    ///
-    pub fn new() -> Self {
+    /// [1]: Multiton::instance
+    pub fn new(key: &CoreKey) -> Self {
         Self {
-            mediator_map: RefCell::new(BTreeMap::new()),
-            observer_map: RefCell::new(HashMap::new()),
+            key: key.clone(),
+            mediator_map: Lock::new(BTreeMap::new()),
+            named_mediators: Lock::new(BTreeMap::new()),
+            observer_map: Lock::new(HashMap::new()),
+            pattern_observers: Lock::new(PatternObservers::new()),
+            next_order: AtomicU64::new(0),
+        }
+    }
+
+    /// Retrieve the Multiton Key of the [View]
+    pub fn key(&self) -> &CoreKey {
+        &self.key
+    }
+
+    /// Describe the registered [Mediator]'s, the ones registered by type go first
+    pub fn mediators(&self) -> Vec<MediatorInfo> {
+        let mediator_map = self.mediator_map.borrow();
+        let named_mediators = self.named_mediators.borrow();
+
+        let typed = mediator_map.values().map(|mediator| mediator.describe(None));
+        let named = named_mediators
+            .iter()
+            .map(|(name, named)| named.mediator.describe(Some(name.clone())));
+
+        typed.chain(named).collect()
+    }
+
+    /// Retrieve the number of the registered [Mediator]'s
+    pub fn mediator_count(&self) -> usize {
+        self.mediator_map.borrow().len() + self.named_mediators.borrow().len()
+    }
+
+    /// List the [Interest]'s with the number of their [Observer]'s, in the order of the interests.
+    ///
+    /// The wildcard [Observer]'s are not counted, see [pattern_observer_count](BaseView::pattern_observer_count).
+    pub fn observed_interests(&self) -> Vec<(Interest, usize)> {
+        let mut interests: Vec<_> = self
+            .observer_map
+            .borrow()
+            .iter()
+            .filter(|(_, observers)| !observers.is_empty())
+            .map(|(interest, observers)| (*interest, observers.len()))
+            .collect();
+        interests.sort();
+        interests
+    }
+
+    /// Retrieve the number of the [Observer]'s notified of the [Interest], including the wildcard ones
+    pub fn observer_count(&self, interest: Interest) -> usize {
+        let mut observers = self.observer_map.borrow().get(&interest).cloned().unwrap_or_default();
+        self.pattern_observers.borrow().collect(interest, &mut observers);
+        observers.len()
+    }
+
+    /// List the [InterestPattern]'s with the number of their wildcard [Observer]'s
+    pub fn observed_patterns(&self) -> Vec<(InterestPattern, usize)> {
+        let pattern_observers = self.pattern_observers.borrow();
+
+        let mut patterns = Vec::new();
+        if !pattern_observers.all.is_empty() {
+            patterns.push((InterestPattern::All, pattern_observers.all.len()));
+        }
+
+        let mut categories: Vec<_> = pattern_observers
+            .categories
+            .iter()
+            .map(|(category, observers)| (InterestPattern::Category(*category), observers.len()))
+            .collect();
+        categories.sort_by_key(|(pattern, _)| match pattern {
+            InterestPattern::Category(category) => *category,
+            _ => 0,
+        });
+        patterns.extend(categories);
+
+        for (pattern, _) in pattern_observers.others.iter() {
+            match patterns.iter_mut().find(|(other, _)| other == pattern) {
+                Some((_, count)) => *count += 1,
+                None => patterns.push((*pattern, 1)),
+            }
+        }
+
+        patterns
+    }
+
+    /// Retrieve the number of the wildcard [Observer]'s
+    pub fn pattern_observer_count(&self) -> usize {
+        let pattern_observers = self.pattern_observers.borrow();
+        pattern_observers.all.len()
+            + pattern_observers.categories.values().map(Vec::len).sum::<usize>()
+            + pattern_observers.others.len()
+    }
+
+    fn entry(&self, observer: Shared<dyn Observer<Body>>, priority: Priority) -> ObserverEntry<Body> {
+        ObserverEntry {
+            priority,
+            order: self.next_order.fetch_add(1, Ordering::Relaxed),
+            observer,
+        }
+    }
+
+    // Register the Mediator as the Observer of its Notification interests
+    fn attach_mediator<M: Mediator<Body>>(&self, mediator: &Shared<M>, context: Shared<dyn NotifyContext>) {
+        mediator.initialize_notifier(&self.key);
+
+        // Get Notification interests, if any.
+        let interests = mediator.list_notification_interests();
+        if !interests.is_empty() {
+            let priority = mediator.notification_priority();
+            let mediator = mediator.clone();
+            // Create Observer
+            let observer = Shared::new(BaseObserver::new(
+                Box::new(move |notification| {
+                    log::info!("Observer notify {:?}", notification);
+                    mediator.handle_notification(notification);
+                    Propagation::Continue
+                }),
+                context,
+            ));
+
+            // Register Mediator as Observer for its list of Notification interests
+            for interest in interests.iter() {
+                self.register_observer_with_priority(*interest, observer.clone(), priority);
+            }
+        }
+    }
+
+    // Remove the Observers linking the Mediator to its Notification interests
+    fn detach_mediator<M: Mediator<Body>>(&self, mediator: &Shared<M>, context: &Shared<dyn NotifyContext>) {
+        // for every notification this mediator is interested in...
+        let interests = mediator.list_notification_interests();
+        for interest in interests.iter() {
+            // remove the observer linking the mediator
+            // to the notification interest
+            self.remove_observer(interest, context);
         }
     }
 }
 
-impl<Body> Singleton for BaseView<Body>
+impl<Body> Multiton for BaseView<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    /// View Singleton Factory method
+    /// View Multiton Factory method
     ///
-    /// It is not possible to implement Singleton with generics.
-    /// So you should implement it in your final code only.
-    ///
-    /// Error: use of generic parameter from outer function
-    ///
-    fn global() -> &'static Self {
-        // static BASE_VIEW_INSTANCE: OnceCell<BaseFacade<Body>> = OnceCell::new();
-        // BASE_VIEW_INSTANCE.get_or_init(Self::new)
-        todo!("you have to reimplement the view for your purposes")
+    fn instance(key: impl Into<CoreKey>) -> Shared<Self> {
+        Core::instance(&key.into(), Self::new)
+    }
+}
+
+impl<Body> CoreActor for BaseView<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn on_remove_core(&self) {
+        self.observer_map.borrow_mut().clear();
+        self.pattern_observers.replace(PatternObservers::new());
+
+        let mediators = std::mem::take(&mut *self.mediator_map.borrow_mut());
+        let named_mediators = std::mem::take(&mut *self.named_mediators.borrow_mut());
+
+        let named_mediators = named_mediators.values().map(|named| &named.mediator);
+        for mediator in mediators.values().chain(named_mediators) {
+            mediator.on_remove();
+        }
     }
 }
 
@@ -77,54 +387,112 @@ impl<Body> View<Body> for BaseView<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    fn notify(&self, note: Rc<dyn Notification<Body>>) {
+    fn notify(&self, note: Shared<dyn Notification<Body>>) {
         // Copy observers from reference array to working array,
         // since the reference array may change during the notification loop
         // and prevent double borrow ))
-        let observers = {
-            self.observer_map
-                .borrow()
-                .get(&note.interest())
-                .map(|observers| observers.clone())
-        };
-
-        if let Some(observers) = observers {
-            for observer in observers.iter() {
-                log::info!("Notify observer {:?} for {:?}", observer, note.interest());
-                observer.notify(note.clone());
+        let interest = note.interest();
+        let mut observers = self.observer_map.borrow().get(&interest).cloned().unwrap_or_default();
+
+        // the wildcard observers are merged with the exact ones by priority
+        {
+            let pattern_observers = self.pattern_observers.borrow();
+            if !pattern_observers.is_empty() {
+                let exact = observers.len();
+                pattern_observers.collect(interest, &mut observers);
+                if observers.len() > exact {
+                    observers.sort_by_key(|entry| (Reverse(entry.priority), entry.order));
+                }
+            }
+        }
+
+        for entry in observers.iter() {
+            log::info!("Notify observer {:?} for {:?}", entry.observer, interest);
+            if entry.observer.notify(note.clone()) == Propagation::Stop {
+                log::info!("Stop notification {:?}", interest);
+                break;
             }
         }
     }
 
-    fn register_observer(&self, interest: Interest, observer: Rc<dyn Observer<Body>>) {
+    fn register_observer(&self, interest: Interest, observer: Shared<dyn Observer<Body>>) {
+        self.register_observer_with_priority(interest, observer, DEFAULT_PRIORITY);
+    }
+
+    fn register_observer_with_priority(
+        &self,
+        interest: Interest,
+        observer: Shared<dyn Observer<Body>>,
+        priority: Priority,
+    ) {
         // log::info!("Register Observer [BaseView] {:?}", interest);
+        let entry = self.entry(observer, priority);
+        insert_observer(self.observer_map.borrow_mut().entry(interest).or_default(), entry);
+    }
+
+    // It private so its fun
+    fn remove_observer(&self, interest: &Interest, context: &Shared<dyn NotifyContext>) {
         let mut observer_map = self.observer_map.borrow_mut();
 
-        if !observer_map.contains_key(&interest) {
-            observer_map.insert(interest.clone(), Vec::new());
+        // the observer list for the notification under inspection
+        if let Some(observers) = observer_map.get_mut(interest) {
+            // find the observer for the notify_context and remove it
+            remove_observer(observers, context);
+
+            // also, when a Notification's Observer list length falls to
+            // zero, delete the notification key from the observer map
+            if observers.is_empty() {
+                observer_map.remove(interest);
+            }
         }
+    }
+}
+
+impl<Body> PatternRegistry<Body> for BaseView<Body>
+where
+    Body: fmt::Debug + 'static,
+{
+    fn register_pattern_observer(
+        &self,
+        pattern: InterestPattern,
+        observer: Shared<dyn Observer<Body>>,
+        priority: Priority,
+    ) {
+        let entry = self.entry(observer, priority);
+        let mut pattern_observers = self.pattern_observers.borrow_mut();
 
-        observer_map
-            .get_mut(&interest)
-            .map(|observers| observers.push(observer));
+        match pattern {
+            InterestPattern::All => insert_observer(&mut pattern_observers.all, entry),
+            InterestPattern::Category(category) => {
+                insert_observer(pattern_observers.categories.entry(category).or_default(), entry)
+            }
+            _ => pattern_observers.others.push((pattern, entry)),
+        }
     }
 
-    // It private so its fun
-    fn remove_observer(&self, interest: &Interest, context: &Rc<dyn NotifyContext>) {
-        let mut observer_map = self.observer_map.borrow_mut();
+    fn remove_pattern_observer(&self, pattern: &InterestPattern, context: &Shared<dyn NotifyContext>) {
+        let mut pattern_observers = self.pattern_observers.borrow_mut();
 
-        // the observer list for the notification under inspection
-        observer_map.remove(interest).as_mut().map(|observers| {
-            // find the observer for the notify_context
-            for (idx, observer) in observers.iter().enumerate() {
-                if observer.compare_context(context) == true {
-                    // there can only be one Observer for a given notify_context
-                    // in any given Observer list, so remove it and break
-                    observers.remove(idx);
-                    break;
+        match pattern {
+            InterestPattern::All => remove_observer(&mut pattern_observers.all, context),
+            InterestPattern::Category(category) => {
+                if let Some(observers) = pattern_observers.categories.get_mut(category) {
+                    remove_observer(observers, context);
+                    if observers.is_empty() {
+                        pattern_observers.categories.remove(category);
+                    }
                 }
             }
-        });
+            _ => {
+                let idx = pattern_observers
+                    .others
+                    .iter()
+                    .position(|(other, entry)| other == pattern && entry.observer.compare_context(context));
+                if let Some(idx) = idx {
+                    pattern_observers.others.remove(idx);
+                }
+            }
+        }
     }
 }
 
@@ -132,51 +500,35 @@ impl<Body> MediatorRegistry<Body> for BaseView<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    fn register_mediator<M: Mediator<Body>>(&self, mediator: Rc<M>) {
+    fn register_mediator<M: Mediator<Body>>(&self, mediator: Shared<M>) {
         log::info!("Register Mediator [BaseView] {:?}", mediator);
 
-        let mut mediator_map = self.mediator_map.borrow_mut();
-
         let type_id = TypeId::of::<M>();
-        // do not allow re-registration (you must to removeMediator fist)
-        if mediator_map.contains_key(&type_id) {
-            return;
-        }
 
-        // Register the Mediator for retrieval by name
-        mediator_map.insert(type_id, mediator.clone());
-
-        // Get Notification interests, if any.
-        let interests = mediator.list_notification_interests();
-        if interests.len() > 0 {
-            let mediator = mediator.clone();
-            let context = mediator.clone();
-            // Create Observer
-            let observer = Rc::new(BaseObserver::new(
-                Box::new(move |notification| {
-                    log::info!("Observer notify {:?}", notification);
-                    mediator.handle_notification(notification.clone())
-                }),
-                context,
-            ));
+        {
+            let mut mediator_map = self.mediator_map.borrow_mut();
 
-            // Register Mediator as Observer for its list of Notification interests
-            for interest in interests.iter() {
-                self.register_observer(interest.clone(), observer.clone());
+            // do not allow re-registration (you must to removeMediator fist)
+            if mediator_map.contains_key(&type_id) {
+                return;
             }
+
+            // Register the Mediator for retrieval by name
+            mediator_map.insert(type_id, mediator.clone());
         }
 
+        self.attach_mediator(&mediator, mediator.clone());
         mediator.on_register();
     }
 
-    fn retrieve_mediator<M: Mediator<Body>>(&self) -> Option<Rc<M>> {
+    fn retrieve_mediator<M: Mediator<Body>>(&self) -> Option<Shared<M>> {
         let type_id = TypeId::of::<M>();
 
         match self.mediator_map.borrow().get(&type_id) {
-            Some(item) => match item.clone().downcast::<M>() {
-                Ok(mediator) => Some(mediator.clone()),
+            Some(item) => match item.clone().into_any().downcast::<M>() {
+                Ok(mediator) => Some(mediator),
                 Err(_) => {
-                    log::error!("Something wrong with proxy storage");
+                    log::error!("Something wrong with mediator storage");
                     None
                 }
             },
@@ -184,36 +536,16 @@ where
         }
     }
 
-    fn remove_mediator<M: Mediator<Body>>(&self) -> Option<Rc<M>> {
+    fn remove_mediator<M: Mediator<Body>>(&self) -> Option<Shared<M>> {
         // remove the mediator from the map
         let type_id = TypeId::of::<M>();
 
-        self.mediator_map.borrow_mut().remove(&type_id).map(|mediator| {
-            match mediator.downcast::<M>() {
+        let mediator = self.mediator_map.borrow_mut().remove(&type_id);
+
+        mediator.map(|mediator| {
+            match mediator.into_any().downcast::<M>() {
                 Ok(mediator) => {
-                    // for every notification this mediator is interested in...
-                    let interests = mediator.list_notification_interests();
-                    for interest in interests.iter() {
-                        // remove the observer linking the mediator
-                        // to the notification interest
-
-                        let mut observer_map = self.observer_map.borrow_mut();
-
-                        let context = mediator.id();
-
-                        // the observer list for the notification under inspection
-                        observer_map.remove(interest).as_mut().map(|observers| {
-                            // find the observer for the notify_context
-                            for (idx, observer) in observers.iter().enumerate() {
-                                if observer.context().id() == context {
-                                    // there can only be one Observer for a given notify_context
-                                    // in any given Observer list, so remove it and break
-                                    observers.remove(idx);
-                                    break;
-                                }
-                            }
-                        });
-                    }
+                    self.detach_mediator(&mediator, &(mediator.clone() as Shared<dyn NotifyContext>));
 
                     // alert the mediator that it has been removed
                     mediator.on_remove();
@@ -230,4 +562,220 @@ where
         let type_id = TypeId::of::<M>();
         self.mediator_map.borrow().contains_key(&type_id)
     }
+
+    fn register_named_mediator<M: Mediator<Body>>(&self, name: &str, mediator: Shared<M>) {
+        log::info!("Register Mediator [BaseView] {} {:?}", name, mediator);
+
+        let context: Shared<dyn NotifyContext> = Shared::new(NamedContext(
+            NAMED_MEDIATOR_CONTEXT | NEXT_NAMED_MEDIATOR_CONTEXT.fetch_add(1, Ordering::Relaxed),
+        ));
+
+        // the replaced mediator is removed first
+        let previous = self.named_mediators.borrow_mut().remove(name);
+        if let Some(previous) = previous {
+            for interest in previous.mediator.notification_interests().iter() {
+                self.remove_observer(interest, &previous.context);
+            }
+            previous.mediator.on_remove();
+        }
+
+        self.named_mediators.borrow_mut().insert(
+            name.into(),
+            NamedMediator {
+                mediator: mediator.clone(),
+                context: context.clone(),
+            },
+        );
+
+        self.attach_mediator(&mediator, context);
+        mediator.on_register();
+    }
+
+    fn retrieve_named_mediator<M: Mediator<Body>>(&self, name: &str) -> Option<Shared<M>> {
+        let mediator = self.named_mediators.borrow().get(name)?.mediator.clone();
+        mediator.into_any().downcast::<M>().ok()
+    }
+
+    fn remove_named_mediator<M: Mediator<Body>>(&self, name: &str) -> Option<Shared<M>> {
+        let mediator = self.retrieve_named_mediator::<M>(name)?;
+
+        let named = self.named_mediators.borrow_mut().remove(name)?;
+        self.detach_mediator(&mediator, &named.context);
+        mediator.on_remove();
+        Some(mediator)
+    }
+
+    fn has_named_mediator(&self, name: &str) -> bool {
+        self.named_mediators.borrow().contains_key(name)
+    }
+
+    fn mediator_names(&self) -> Vec<String> {
+        self.named_mediators.borrow().keys().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        foundation::patterns::{
+            facade::BaseFacade,
+            observer::{BaseNotification, BaseObserver},
+        },
+        prelude::*,
+    };
+
+    use super::BaseView;
+
+    const CHANGED: Interest = Interest(1);
+
+    #[derive(Debug)]
+    struct Context(u64);
+
+    impl NotifyContext for Context {
+        fn id(&self) -> u64 {
+            self.0
+        }
+    }
+
+    // Records the notifications by its list, all the instances have the same id
+    #[derive(Debug)]
+    struct ListMediator {
+        list: &'static str,
+        notified: Shared<Lock<Vec<&'static str>>>,
+        removed: Lock<bool>,
+    }
+
+    impl NotifyContext for ListMediator {
+        fn id(&self) -> u64 {
+            0x10
+        }
+    }
+
+    impl Mediator<u32> for ListMediator {
+        fn view_component(&self) -> Option<Shared<dyn View<u32>>> {
+            None
+        }
+
+        fn set_view_component(&mut self, _component: Option<Shared<dyn View<u32>>>) {}
+
+        fn list_notification_interests(&self) -> &[Interest] {
+            &[CHANGED]
+        }
+
+        fn handle_notification(&self, _notification: Shared<dyn Notification<u32>>) {
+            self.notified.borrow_mut().push(self.list);
+        }
+
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {
+            *self.removed.borrow_mut() = true;
+        }
+    }
+
+    fn observer(id: u64, notified: &Shared<Lock<Vec<u64>>>, propagation: Propagation) -> Shared<dyn Observer<u32>> {
+        let notified = notified.clone();
+        Shared::new(BaseObserver::new(
+            Box::new(move |_| {
+                notified.borrow_mut().push(id);
+                propagation
+            }),
+            Shared::new(Context(id)),
+        ))
+    }
+
+    #[test]
+    fn should_notify_observers_by_priority() {
+        let view = BaseView::<u32>::instance("observer-priority");
+        let notified = Shared::new(Lock::new(Vec::new()));
+
+        view.register_observer(CHANGED, observer(1, &notified, Propagation::Continue));
+        view.register_observer_with_priority(CHANGED, observer(2, &notified, Propagation::Continue), 10);
+        view.register_observer(CHANGED, observer(3, &notified, Propagation::Continue));
+        view.register_observer_with_priority(CHANGED, observer(4, &notified, Propagation::Continue), -5);
+
+        view.notify(Shared::new(BaseNotification::new(CHANGED, None)));
+        assert_eq!(notified.replace(Vec::new()), vec![2, 1, 3, 4]);
+
+        view.register_observer_with_priority(CHANGED, observer(5, &notified, Propagation::Stop), 5);
+
+        view.notify(Shared::new(BaseNotification::new(CHANGED, None)));
+        assert_eq!(notified.replace(Vec::new()), vec![2, 5]);
+
+        BaseFacade::<u32>::remove_core("observer-priority");
+    }
+
+    #[test]
+    fn should_notify_pattern_observers() {
+        let view = BaseView::<u32>::instance("pattern-observers");
+        let notified = Shared::new(Lock::new(Vec::new()));
+
+        let loaded = Interest::new(2, 1);
+        assert_eq!(loaded.category(), 2);
+        assert_eq!(loaded.code(), 1);
+
+        view.register_observer(loaded, observer(1, &notified, Propagation::Continue));
+        view.register_pattern_observer(InterestPattern::All, observer(2, &notified, Propagation::Continue), -1);
+        view.register_pattern_observer(InterestPattern::Category(2), observer(3, &notified, Propagation::Continue), 1);
+        view.register_pattern_observer(
+            InterestPattern::Range(Interest(10), Interest(20)),
+            observer(4, &notified, Propagation::Continue),
+            DEFAULT_PRIORITY,
+        );
+
+        view.notify(Shared::new(BaseNotification::new(loaded, None)));
+        assert_eq!(notified.replace(Vec::new()), vec![3, 1, 2]);
+
+        view.notify(Shared::new(BaseNotification::new(Interest(15), None)));
+        assert_eq!(notified.replace(Vec::new()), vec![4, 2]);
+
+        view.remove_pattern_observer(&InterestPattern::All, &(Shared::new(Context(2)) as Shared<dyn NotifyContext>));
+        view.notify(Shared::new(BaseNotification::new(Interest(30), None)));
+        assert!(notified.borrow().is_empty());
+
+        BaseFacade::<u32>::remove_core("pattern-observers");
+    }
+
+    #[test]
+    fn should_register_mediators_by_name() {
+        let view = BaseView::<u32>::instance("named-mediators");
+        let notified = Shared::new(Lock::new(Vec::new()));
+
+        for list in ["users", "groups"] {
+            let notified = notified.clone();
+            let removed = Lock::new(false);
+            view.register_named_mediator(list, Shared::new(ListMediator { list, notified, removed }));
+        }
+
+        assert_eq!(view.mediator_names(), vec!["groups".to_string(), "users".to_string()]);
+        assert_eq!(view.retrieve_named_mediator::<ListMediator>("users").map(|mediator| mediator.list), Some("users"));
+        assert!(!view.has_mediator::<ListMediator>());
+
+        view.notify(Shared::new(BaseNotification::new(CHANGED, None)));
+        assert_eq!(notified.replace(Vec::new()), vec!["users", "groups"]);
+
+        assert!(view.remove_named_mediator::<ListMediator>("users").is_some());
+        assert!(!view.has_named_mediator("users"));
+
+        view.notify(Shared::new(BaseNotification::new(CHANGED, None)));
+        // the mediators of the same id are removed separately
+        assert_eq!(notified.replace(Vec::new()), vec!["groups"]);
+
+        // the replaced mediator is removed
+        let groups = view.retrieve_named_mediator::<ListMediator>("groups").unwrap();
+        view.register_named_mediator(
+            "groups",
+            Shared::new(ListMediator {
+                list: "admins",
+                notified: notified.clone(),
+                removed: Lock::new(false),
+            }),
+        );
+        assert!(*groups.removed.borrow());
+
+        view.notify(Shared::new(BaseNotification::new(CHANGED, None)));
+        assert_eq!(notified.replace(Vec::new()), vec!["admins"]);
+
+        BaseFacade::<u32>::remove_core("named-mediators");
+    }
 }
@@ -1,31 +1,40 @@
-// use once_cell::sync::OnceCell;
-use std::{fmt::Debug, rc::Rc};
+use std::fmt::Debug;
 
 use crate::{
     foundation::patterns::{
-        default::{BaseController, BaseModel, BaseView},
-        observer::{BaseNotification, BaseObserver},
+        command::CommandHistory,
+        default::{BaseController, BaseModel, BaseView, Core, CoreActor, CoreReport, ModelSnapshot, SnapshotError},
+        message::TypedCommandAdapter,
+        observer::BaseNotification,
     },
     prelude::*,
 };
 
-/// A base Singleton [Facade] implementation.
+/// A base Multiton [Facade] implementation.
 ///
 /// In PureMVC, the [Facade] class assumes these
 /// responsibilities:
 ///
-/// - Initializing the [Model], [View] and [Controller] Singletons.
+/// - Initializing the [Model], [View] and [Controller] Multitons.
 /// - Providing all the methods defined by the [Model], [View], & [Controller] interfaces.
 /// - Providing a single point of contact to the application for registering [Command]'s and notifying [Observer]'s
 ///
-
+/// The [Notification]'s sent through the [Facade] pass the chain of its [Middleware]'s
+/// before they are broadcast by the [View].
 pub struct BaseFacade<Body>
 where
     Body: Debug + 'static,
 {
+    // The Multiton Key for this Core
+    key: CoreKey,
+
     // Private references to Model, View and Controller
-    controller: BaseController<Body>,
-    view: BaseView<Body>,
+    model: Shared<BaseModel>,
+    view: Shared<BaseView<Body>>,
+    controller: Shared<BaseController<Body>>,
+
+    // Chain of middlewares between send and notify
+    middlewares: Lock<Vec<Shared<dyn Middleware<Body>>>>,
 }
 
 impl<Body> BaseFacade<Body>
@@ -34,85 +43,153 @@ where
 {
     /// Create instance of BaseFacade.
     ///
-    /// Actually, you have to reimplement the [Facade] for your purposes with Singleton pattern.
+    /// This [Facade] implementation is a Multiton, so you should not call the constructor
+    /// directly, but instead call the static Multiton Factory method [instance()][1]
     ///
-    /// This [Facade] implementation is keept here in educational purposes only.
-
-    pub fn new() -> Self {
+    /// [1]: Multiton::instance
+    pub fn new(key: &CoreKey) -> Self {
         Self {
-            controller: BaseController::new(),
-            view: BaseView::new(),
+            key: key.clone(),
+            model: BaseModel::instance(key),
+            view: BaseView::instance(key),
+            controller: BaseController::instance(key),
+            middlewares: Lock::new(Vec::new()),
         }
     }
+
+    /// Retrieve the Multiton Key of the [Facade]
+    pub fn key(&self) -> &CoreKey {
+        &self.key
+    }
+
+    /// Retrieve the undo history of the [UndoableCommand]'s
+    pub fn history(&self) -> &CommandHistory<Body> {
+        self.controller.history()
+    }
+
+    /// Set the [Spawner] which drives the [AsyncCommand]'s triggered by [send](Facade::send)
+    pub fn set_spawner(&self, spawner: Shared<dyn Spawner>) {
+        self.controller.set_spawner(spawner);
+    }
+
+    /// Register the [SnapshotProxy] with the [Model], see [BaseModel::register_snapshot_proxy]
+    pub fn register_snapshot_proxy<P: SnapshotProxy>(&self, proxy: Shared<P>) -> Result<(), SnapshotError> {
+        self.model.register_snapshot_proxy(proxy)
+    }
+
+    /// Capture the [SnapshotProxy]'s of the [Model], see [BaseModel::snapshot]
+    pub fn snapshot(&self) -> Result<ModelSnapshot, serde_json::Error> {
+        self.model.snapshot()
+    }
+
+    /// Restore the [SnapshotProxy]'s of the [Model], see [BaseModel::restore]
+    pub fn restore(&self, snapshot: &ModelSnapshot) -> Result<(), serde_json::Error> {
+        self.model.restore(snapshot)
+    }
+
+    /// Describe everything registered with the Core
+    pub fn describe(&self) -> CoreReport {
+        CoreReport {
+            key: self.key.clone(),
+            proxies: self.model.proxies(),
+            mediators: self.view.mediators(),
+            commands: self.controller.commands(),
+            observers: self.view.observed_interests(),
+            patterns: self.view.observed_patterns(),
+        }
+    }
+
+    /// Check if a Core is registered or not
+    pub fn has_core(key: impl Into<CoreKey>) -> bool {
+        Core::has_core(&key.into())
+    }
+
+    /// Remove a Core.
+    ///
+    /// Remove the [Model], [View], [Controller] and [Facade] instances for the given key,
+    /// calling `on_remove` on every registered [Proxy] and [Mediator].
+    pub fn remove_core(key: impl Into<CoreKey>) {
+        Core::remove_core(&key.into());
+    }
 }
 
-impl<Body> Singleton for BaseFacade<Body>
+impl<Body> Multiton for BaseFacade<Body>
 where
     Body: Debug + 'static,
 {
-    /// Facade Singleton Factory method
-    ///
-    /// It is not possible to implement Singleton with generics.
-    /// So you should implement it in your final code only.
-    ///
-    /// Error: use of generic parameter from outer function
+    /// Facade Multiton Factory method
     ///
-    fn global() -> &'static Self {
-        // static BASE_FACADE_INSTANCE: OnceCell<BaseFacade<Body>> = OnceCell::new();
-        // BASE_FACADE_INSTANCE.get_or_init(Self::new)
-        todo!("you have to reimplement the facade for your purposes")
+    fn instance(key: impl Into<CoreKey>) -> Shared<Self> {
+        Core::instance(&key.into(), Self::new)
     }
 }
 
-impl<Body> Facade<Body> for BaseFacade<Body>
+impl<Body> CoreActor for BaseFacade<Body>
 where
     Body: Debug + 'static,
+{
+    // The Model, View and Controller are actors of the same Core,
+    // so they are torn down by the Core itself
+    fn on_remove_core(&self) {
+        self.middlewares.borrow_mut().clear();
+    }
+}
+
+impl<Body> Facade<Body> for BaseFacade<Body>
+where
+    Body: Debug + MaybeSendSync + 'static,
 {
     fn has_command(&self, interest: &Interest) -> bool {
         self.controller.has_command(interest)
     }
 
-    fn register_command(&self, interest: Interest, command: Rc<dyn Command<Body>>) {
-        {
-            // this code should be located in [Controller] in your final version
-            // check the [BaseController]::register_command
-
-            if !self.has_command(&interest) {
-                self.view.register_observer(
-                    interest,
-                    Rc::new(BaseObserver::new(
-                        Box::new(|notification| {
-                            log::error!("You should implement yourself Facade");
-                            BaseFacade::<Body>::global()
-                                .controller
-                                .execute_command(notification);
-                        }),
-                        self.controller.as_context(),
-                    )),
-                );
-            }
-        }
-
+    fn register_command(&self, interest: Interest, command: Shared<dyn Command<Body>>) {
         self.controller.register_command(interest, command);
     }
 
+    fn register_async_command(&self, interest: Interest, command: Shared<dyn AsyncCommand<Body>>) {
+        self.controller.register_async_command(interest, command);
+    }
+
+    fn register_undoable_command(&self, interest: Interest, command: Shared<dyn UndoableCommand<Body>>) {
+        self.controller.register_undoable_command(interest, command);
+    }
+
     fn remove_command(&self, interest: &Interest) {
-        if self.has_command(interest) {
-            {
-                // this code should be located in [Controller] in your final version
-                // check the [BaseController]::remove_command
-
-                // remove the observer
-                self.view
-                    .remove_observer(interest, &self.controller.as_context());
-            }
+        self.controller.remove_command(interest);
+    }
 
-            self.controller.remove_command(interest);
-        }
+    fn register_middleware(&self, middleware: Shared<dyn Middleware<Body>>) {
+        self.middlewares.borrow_mut().push(middleware);
     }
 
     fn send(&self, interest: Interest, body: Option<Body>) {
-        self.notify(Rc::new(BaseNotification::new(interest, body)));
+        let note = Shared::new(BaseNotification::new(interest, body));
+
+        // the chain is copied, as the middlewares may register other middlewares
+        let middlewares = self.middlewares.borrow().clone();
+        if middlewares.is_empty() {
+            self.notify(note);
+        } else {
+            Next::new(middlewares, self.view.clone()).run(note);
+        }
+    }
+
+    fn send_async(&self, interest: Interest, body: Option<Body>) -> BoxFuture<'static, ()> {
+        Box::pin(BaseController::<Body>::join_triggered(|| self.send(interest, body)))
+    }
+}
+
+impl<Body> TypedFacade<Body> for BaseFacade<Body>
+where
+    Body: TypedBody + MaybeSendSync,
+{
+    fn register_typed_command<M: Message>(&self, command: Shared<dyn TypedCommand<M>>) {
+        self.register_command(M::INTEREST, Shared::new(TypedCommandAdapter::<M, Body>::new(command)));
+    }
+
+    fn send_typed<M: Message>(&self, body: M::Body) {
+        self.send(M::INTEREST, Some(Body::from_message::<M>(body)));
     }
 }
 
@@ -122,22 +199,42 @@ where
 {
     /// Check if a [Proxy] is registered
     fn has_proxy<P: Proxy>(&self) -> bool {
-        BaseModel::global().has_proxy::<P>()
+        self.model.has_proxy::<P>()
     }
 
     /// Register an [Proxy] with the [Model] by name.
-    fn register_proxy<P: Proxy>(&self, proxy: Rc<P>) {
-        BaseModel::global().register_proxy(proxy);
+    fn register_proxy<P: Proxy>(&self, proxy: Shared<P>) {
+        self.model.register_proxy(proxy);
     }
 
     /// Remove an [Proxy] instance from the [Model] by name.
-    fn remove_proxy<P: Proxy>(&self) -> Option<Rc<P>> {
-        BaseModel::global().remove_proxy::<P>()
+    fn remove_proxy<P: Proxy>(&self) -> Option<Shared<P>> {
+        self.model.remove_proxy::<P>()
     }
 
     /// Retrieve a [Proxy] from the [Model] by name.
-    fn retrieve_proxy<P: Proxy>(&self) -> Option<Rc<P>> {
-        BaseModel::global().retrieve_proxy::<P>()
+    fn retrieve_proxy<P: Proxy>(&self) -> Option<Shared<P>> {
+        self.model.retrieve_proxy::<P>()
+    }
+
+    fn register_named_proxy<P: Proxy>(&self, name: &str, proxy: Shared<P>) {
+        self.model.register_named_proxy(name, proxy);
+    }
+
+    fn retrieve_named_proxy<P: Proxy>(&self, name: &str) -> Option<Shared<P>> {
+        self.model.retrieve_named_proxy::<P>(name)
+    }
+
+    fn remove_named_proxy<P: Proxy>(&self, name: &str) -> Option<Shared<P>> {
+        self.model.remove_named_proxy::<P>(name)
+    }
+
+    fn has_named_proxy(&self, name: &str) -> bool {
+        self.model.has_named_proxy(name)
+    }
+
+    fn proxy_names(&self) -> Vec<String> {
+        self.model.proxy_names()
     }
 }
 
@@ -145,11 +242,20 @@ impl<Body> View<Body> for BaseFacade<Body>
 where
     Body: Debug + 'static,
 {
-    fn register_observer(&self, interest: Interest, observer: Rc<dyn Observer<Body>>) {
+    fn register_observer(&self, interest: Interest, observer: Shared<dyn Observer<Body>>) {
         self.view.register_observer(interest, observer);
     }
 
-    fn remove_observer(&self, interest: &Interest, notify_context: &Rc<dyn NotifyContext>) {
+    fn register_observer_with_priority(
+        &self,
+        interest: Interest,
+        observer: Shared<dyn Observer<Body>>,
+        priority: Priority,
+    ) {
+        self.view.register_observer_with_priority(interest, observer, priority);
+    }
+
+    fn remove_observer(&self, interest: &Interest, notify_context: &Shared<dyn NotifyContext>) {
         self.view.remove_observer(interest, notify_context);
     }
 
@@ -157,32 +263,50 @@ where
     ///
     /// All previously attached [Observer]'s for this [Notification]'s
     /// list are notified and are passed a reference to the [Notification] in
-    /// the order in which they were registered.
+    /// the order of their priorities, until one of them stops the propagation.
     ///
     /// NOTE: Use this method only if you are sending custom Notifications. Otherwise
     /// use the sendNotification method which does not require you to create the
     /// Notification instance.
-    fn notify(&self, note: Rc<dyn Notification<Body>>) {
+    fn notify(&self, note: Shared<dyn Notification<Body>>) {
         self.view.notify(note);
     }
 }
 
+impl<Body> PatternRegistry<Body> for BaseFacade<Body>
+where
+    Body: Debug + 'static,
+{
+    fn register_pattern_observer(
+        &self,
+        pattern: InterestPattern,
+        observer: Shared<dyn Observer<Body>>,
+        priority: Priority,
+    ) {
+        self.view.register_pattern_observer(pattern, observer, priority);
+    }
+
+    fn remove_pattern_observer(&self, pattern: &InterestPattern, notify_context: &Shared<dyn NotifyContext>) {
+        self.view.remove_pattern_observer(pattern, notify_context);
+    }
+}
+
 impl<Body> MediatorRegistry<Body> for BaseFacade<Body>
 where
     Body: Debug + 'static,
 {
     /// Register an [Mediator] instance with the [View].
-    fn register_mediator<M: Mediator<Body>>(&self, mediator: Rc<M>) {
+    fn register_mediator<M: Mediator<Body>>(&self, mediator: Shared<M>) {
         self.view.register_mediator(mediator);
     }
 
     /// Retrieve an [Mediator] instance from the [View].
-    fn retrieve_mediator<M: Mediator<Body>>(&self) -> Option<Rc<M>> {
+    fn retrieve_mediator<M: Mediator<Body>>(&self) -> Option<Shared<M>> {
         self.view.retrieve_mediator::<M>()
     }
 
     /// Remove a [Mediator] instance from the [View].
-    fn remove_mediator<M: Mediator<Body>>(&self) -> Option<Rc<M>> {
+    fn remove_mediator<M: Mediator<Body>>(&self) -> Option<Shared<M>> {
         self.view.remove_mediator::<M>()
     }
 
@@ -190,4 +314,322 @@ where
     fn has_mediator<M: Mediator<Body>>(&self) -> bool {
         self.view.has_mediator::<M>()
     }
+
+    fn register_named_mediator<M: Mediator<Body>>(&self, name: &str, mediator: Shared<M>) {
+        self.view.register_named_mediator(name, mediator);
+    }
+
+    fn retrieve_named_mediator<M: Mediator<Body>>(&self, name: &str) -> Option<Shared<M>> {
+        self.view.retrieve_named_mediator::<M>(name)
+    }
+
+    fn remove_named_mediator<M: Mediator<Body>>(&self, name: &str) -> Option<Shared<M>> {
+        self.view.remove_named_mediator::<M>(name)
+    }
+
+    fn has_named_mediator(&self, name: &str) -> bool {
+        self.view.has_named_mediator(name)
+    }
+
+    fn mediator_names(&self) -> Vec<String> {
+        self.view.mediator_names()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::Pin,
+        sync::atomic::{AtomicU32, Ordering},
+        task::{Context, Poll},
+    };
+
+    use crate::{
+        foundation::patterns::{
+            command::{block_on, LocalExecutor},
+            facade::BaseFacade,
+            default::CommandKind,
+            middleware::SamplingMiddleware,
+            observer::{BaseNotification, BaseObserver},
+            proxy::BaseProxy,
+        },
+        prelude::*,
+    };
+
+    const STARTUP: Interest = Interest(1);
+    const FETCH: Interest = Interest(2);
+
+    #[derive(Debug, Default)]
+    struct StartupCommand {
+        executed: Shared<AtomicU32>,
+    }
+
+    impl Command<u32> for StartupCommand {
+        fn execute(&self, notification: Shared<dyn Notification<u32>>) {
+            self.executed
+                .fetch_add(notification.body().copied().unwrap_or_default(), Ordering::SeqCst);
+        }
+    }
+
+    // Yields to the executor once
+    #[derive(Default)]
+    struct YieldNow {
+        yielded: bool,
+    }
+
+    impl Future for YieldNow {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            if self.yielded {
+                Poll::Ready(())
+            } else {
+                self.yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct FetchCommand {
+        fetched: Shared<AtomicU32>,
+    }
+
+    impl AsyncCommand<u32> for FetchCommand {
+        fn execute(&self, notification: Shared<dyn Notification<u32>>) -> BoxFuture<'static, ()> {
+            let fetched = self.fetched.clone();
+            let amount = notification.body().copied().unwrap_or_default();
+
+            Box::pin(async move {
+                YieldNow::default().await;
+                fetched.fetch_add(amount, Ordering::SeqCst);
+            })
+        }
+    }
+
+    // Sends the doubled body twice
+    #[derive(Debug)]
+    struct FanOutMiddleware;
+
+    impl Middleware<u32> for FanOutMiddleware {
+        fn handle(&self, notification: Shared<dyn Notification<u32>>, next: Next<u32>) {
+            let body = notification.body().map(|body| body * 2);
+            let note: Shared<dyn Notification<u32>> = Shared::new(BaseNotification::new(notification.interest(), body));
+
+            next.run(note.clone());
+            next.run(note);
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct StartupMediator {
+        notified: Shared<AtomicU32>,
+    }
+
+    impl NotifyContext for StartupMediator {
+        fn id(&self) -> u64 {
+            0x01
+        }
+    }
+
+    impl Mediator<u32> for StartupMediator {
+        fn view_component(&self) -> Option<Shared<dyn View<u32>>> {
+            None
+        }
+
+        fn set_view_component(&mut self, _component: Option<Shared<dyn View<u32>>>) {}
+
+        fn list_notification_interests(&self) -> &[Interest] {
+            &[STARTUP]
+        }
+
+        fn handle_notification(&self, _notification: Shared<dyn Notification<u32>>) {
+            self.notified.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {}
+    }
+
+    #[test]
+    fn should_execute_command_on_send() {
+        let facade = BaseFacade::<u32>::instance("execute-command");
+
+        let command = Shared::new(StartupCommand::default());
+        let executed = command.executed.clone();
+
+        facade.register_command(STARTUP, command);
+        assert!(facade.has_command(&STARTUP));
+
+        facade.send(STARTUP, Some(2));
+        facade.send(STARTUP, Some(3));
+        assert_eq!(executed.load(Ordering::SeqCst), 5);
+
+        facade.remove_command(&STARTUP);
+        assert!(!facade.has_command(&STARTUP));
+
+        facade.send(STARTUP, Some(7));
+        assert_eq!(executed.load(Ordering::SeqCst), 5);
+
+        BaseFacade::<u32>::remove_core("execute-command");
+    }
+
+    #[test]
+    fn should_keep_mediator_observer_on_command_removal() {
+        let facade = BaseFacade::<u32>::instance("keep-mediator");
+
+        let mediator = Shared::new(StartupMediator::default());
+        let notified = mediator.notified.clone();
+
+        facade.register_mediator(mediator);
+        facade.register_command(STARTUP, Shared::new(StartupCommand::default()));
+        facade.remove_command(&STARTUP);
+
+        facade.send(STARTUP, None);
+        assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+        BaseFacade::<u32>::remove_core("keep-mediator");
+    }
+
+    #[test]
+    fn should_resolve_send_async_when_commands_finished() {
+        let facade = BaseFacade::<u32>::instance("send-async");
+
+        let command = Shared::new(FetchCommand::default());
+        let fetched = command.fetched.clone();
+
+        facade.register_async_command(FETCH, command);
+        assert!(facade.has_command(&FETCH));
+
+        let pending = facade.send_async(FETCH, Some(2));
+        assert_eq!(fetched.load(Ordering::SeqCst), 0);
+
+        block_on(pending);
+        assert_eq!(fetched.load(Ordering::SeqCst), 2);
+
+        // sync commands are done before send_async returns
+        let command = Shared::new(StartupCommand::default());
+        let executed = command.executed.clone();
+        facade.register_command(STARTUP, command);
+
+        block_on(facade.send_async(STARTUP, Some(3)));
+        assert_eq!(executed.load(Ordering::SeqCst), 3);
+
+        BaseFacade::<u32>::remove_core("send-async");
+    }
+
+    #[test]
+    fn should_spawn_async_commands_on_send() {
+        let facade = BaseFacade::<u32>::instance("spawn-async");
+
+        let executor = Shared::new(LocalExecutor::new());
+        facade.set_spawner(executor.clone());
+
+        let command = Shared::new(FetchCommand::default());
+        let fetched = command.fetched.clone();
+        facade.register_async_command(FETCH, command);
+
+        facade.send(FETCH, Some(2));
+        facade.send(FETCH, Some(3));
+        assert_eq!(executor.len(), 2);
+        assert_eq!(fetched.load(Ordering::SeqCst), 0);
+
+        executor.run();
+        assert!(executor.is_empty());
+        assert_eq!(fetched.load(Ordering::SeqCst), 5);
+
+        BaseFacade::<u32>::remove_core("spawn-async");
+    }
+
+    #[test]
+    fn should_pass_notifications_through_middlewares() {
+        let facade = BaseFacade::<u32>::instance("middlewares");
+
+        let command = Shared::new(StartupCommand::default());
+        let executed = command.executed.clone();
+        facade.register_command(STARTUP, command);
+
+        facade.register_middleware(Shared::new(FanOutMiddleware));
+        facade.send(STARTUP, Some(1));
+        assert_eq!(executed.load(Ordering::SeqCst), 4);
+
+        // the sampling middleware is called after the fan out
+        facade.register_middleware(Shared::new(SamplingMiddleware::new(2)));
+        facade.send(STARTUP, Some(1));
+        assert_eq!(executed.load(Ordering::SeqCst), 6);
+
+        BaseFacade::<u32>::remove_core("middlewares");
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn should_send_from_worker_threads() {
+        let facade = BaseFacade::<u32>::instance("worker-threads");
+
+        let command = Shared::new(StartupCommand::default());
+        let executed = command.executed.clone();
+        facade.register_command(STARTUP, command);
+
+        let workers = (0..4)
+            .map(|_| std::thread::spawn(|| BaseFacade::<u32>::instance("worker-threads").send(STARTUP, Some(1))))
+            .collect::<Vec<_>>();
+
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        assert_eq!(executed.load(Ordering::SeqCst), 4);
+
+        BaseFacade::<u32>::remove_core("worker-threads");
+    }
+
+    #[test]
+    fn should_describe_registrations() {
+        let facade = BaseFacade::<u32>::instance("describe");
+
+        facade.register_proxy(Shared::new(BaseProxy::<u32>::new(None)));
+        facade.register_named_proxy("users", Shared::new(BaseProxy::<u32>::new(None)));
+        facade.register_mediator(Shared::new(StartupMediator::default()));
+        facade.register_command(STARTUP, Shared::new(StartupCommand::default()));
+        facade.register_async_command(FETCH, Shared::new(FetchCommand::default()));
+
+        let report = facade.describe();
+
+        let proxies: Vec<_> = report.proxies.iter().map(|proxy| proxy.name.as_deref()).collect();
+        assert_eq!(proxies, vec![None, Some("users")]);
+        assert!(report.proxies[0].type_name.ends_with("BaseProxy<u32>"));
+
+        assert_eq!(report.mediators.len(), 1);
+        assert!(report.mediators[0].type_name.ends_with("StartupMediator"));
+        assert_eq!(report.mediators[0].interests, vec![STARTUP]);
+
+        let commands: Vec<_> = report.commands.iter().map(|command| (command.interest, command.kind)).collect();
+        assert_eq!(commands, vec![(STARTUP, CommandKind::Sync), (FETCH, CommandKind::Async)]);
+        assert!(report.commands[0].command.starts_with("StartupCommand"));
+
+        // the mediator and the command both observe the startup
+        assert_eq!(report.observers, vec![(STARTUP, 2), (FETCH, 1)]);
+        assert!(report.is_handled(FETCH));
+        assert!(!report.is_handled(Interest(3)));
+
+        assert!(report.to_string().starts_with("Core describe\nProxies (2):"));
+
+        // the wildcard observer handles the interests of its category only
+        let logger = Shared::new(StartupMediator::default());
+        facade.register_pattern_observer(
+            InterestPattern::Category(7),
+            Shared::new(BaseObserver::new(Box::new(|_| Propagation::Continue), logger)),
+            DEFAULT_PRIORITY,
+        );
+
+        let report = facade.describe();
+        assert_eq!(report.patterns, vec![(InterestPattern::Category(7), 1)]);
+        assert!(report.is_handled(Interest::new(7, 1)));
+        assert!(!report.is_handled(Interest(3)));
+
+        BaseFacade::<u32>::remove_core("describe");
+    }
 }
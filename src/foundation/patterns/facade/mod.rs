@@ -8,7 +8,7 @@
 //! The Facade brokers your requests to the Model, View and Controller,
 //! so that your code does not need import those classes and you do not
 //! need to work with them individually. The Facade class automatically
-//! instantiates the Core MVC Singletons in its constructor.
+//! instantiates the Core MVC Multitons in its constructor.
 //! 
 //! Typically, the framework Facade will be sub-classed in your application
 //! and used to initialize the Controller with Command mappings.
@@ -39,7 +39,8 @@
 //! a way that keeps the main application code from knowing much
 //! about the PureMVC apparatus to which it will be connected. The
 //! application merely passes a reference to itself to a ‘startup’ method
-//! on your concrete Facade’s Singleton instance.
+//! on your concrete Facade’s Multiton instance.
 
+#[allow(clippy::module_inception)]
 mod facade;
 pub use self::facade::*;
@@ -0,0 +1,61 @@
+use std::{error::Error, fmt};
+
+/// Represents the failure of the finite state machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsmError {
+    /// The machine has not entered any state yet
+    NotStarted,
+
+    /// The state has not been added to the machine
+    UnknownState(String),
+
+    /// The state has already been added to the machine
+    DuplicateState(String),
+
+    /// The state is not a child of the compound state
+    NotAChild {
+        /// The name of the compound state
+        parent: String,
+        /// The name of the state
+        state: String,
+    },
+
+    /// The current state and its parents have no transition for the input
+    TransitionNotAllowed {
+        /// The name of the current state
+        state: String,
+        /// The name of the event or the target state
+        input: String,
+    },
+
+    /// The guards of all matching transitions rejected the input
+    GuardRejected {
+        /// The name of the current state
+        state: String,
+        /// The name of the event
+        input: String,
+    },
+
+    /// The guard of the pending transition to the state has not allowed it in time
+    TimedOut(String),
+}
+
+impl fmt::Display for FsmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NotStarted => write!(f, "The state machine has not entered any state"),
+            Self::UnknownState(state) => write!(f, "The state {} has not been added", state),
+            Self::DuplicateState(state) => write!(f, "The state {} has already been added", state),
+            Self::NotAChild { parent, state } => write!(f, "The state {} is not a child of {}", state, parent),
+            Self::TransitionNotAllowed { state, input } => {
+                write!(f, "No transition defined from {} for {}", state, input)
+            }
+            Self::GuardRejected { state, input } => {
+                write!(f, "The transition from {} for {} is rejected by the guard", state, input)
+            }
+            Self::TimedOut(state) => write!(f, "The pending transition to {} has timed out", state),
+        }
+    }
+}
+
+impl Error for FsmError {}
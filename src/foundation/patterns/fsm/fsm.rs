@@ -1,6 +1,16 @@
-use std::{any::TypeId, cell::RefCell, collections::HashMap, rc::Rc};
+use std::{
+    any::TypeId,
+    cell::RefCell,
+    collections::{HashMap, VecDeque},
+    fmt,
+    rc::Rc,
+    time::SystemTime,
+};
 
-use super::{integrations::FsmIntegration, State, StateDef, Transitions, Typed};
+use super::{
+    integrations::FsmIntegration, state_def::type_name, FsmError, History, State, StateDef, TransitionRecord,
+    Transitions, Typed,
+};
 
 /// Represents Fsm properties
 #[derive(Default)]
@@ -8,18 +18,78 @@ pub struct FsmProps<T>
 where
     T: FsmIntegration<T>,
 {
-    states: HashMap<TypeId, Rc<StateDef<T>>>,
-    current_state: Option<Rc<StateDef<T>>>,
+    pub(super) states: HashMap<TypeId, Rc<StateDef<T>>>,
+    // The states in the order they are added
+    pub(super) order: Vec<TypeId>,
+    // Initial substates of the compound states
+    pub(super) initial: HashMap<TypeId, TypeId>,
+    // The substates the compound states were last in
+    pub(super) history: HashMap<TypeId, TypeId>,
+    // The compound states resuming their history
+    pub(super) history_kinds: HashMap<TypeId, History>,
+    pub(super) current_state: Option<Rc<StateDef<T>>>,
+    // The transition log, disabled with the zero capacity
+    pub(super) log: VecDeque<TransitionRecord>,
+    pub(super) log_capacity: usize,
+}
+
+impl<T> FsmProps<T>
+where
+    T: FsmIntegration<T>,
+{
+    // The states from the outermost one down to the state
+    pub(super) fn path(&self, state: &Rc<StateDef<T>>) -> Vec<Rc<StateDef<T>>> {
+        let mut path = vec![state.clone()];
+        while let Some(parent) = path.last().and_then(|state| state.parent) {
+            path.push(self.states[&parent].clone());
+        }
+        path.reverse();
+        path
+    }
+
+    // The substates entered below the state, down to the innermost one.
+    // These are the initial substates, unless the compound state resumes its history.
+    pub(super) fn entry_path(&self, state: &Rc<StateDef<T>>) -> Vec<Rc<StateDef<T>>> {
+        let mut path = Vec::new();
+        let mut type_id = state.type_id();
+        let mut deep = false;
+
+        loop {
+            // the deep history resumes all of the nested compound states
+            let kind = self.history_kinds.get(&type_id);
+            deep = deep || kind == Some(&History::Deep);
+
+            let substate = if deep || kind == Some(&History::Shallow) {
+                self.history.get(&type_id)
+            } else {
+                None
+            };
+
+            match substate.or_else(|| self.initial.get(&type_id)) {
+                Some(substate) => {
+                    path.push(self.states[substate].clone());
+                    type_id = *substate;
+                }
+                None => break,
+            }
+        }
+
+        path
+    }
 }
 
 /// Represent finite state machine
+///
+/// The states are able to be nested with [add_child](Fsm::add_child), so a compound state
+/// is entered with its initial substate, and the transitions declared on the compound state
+/// apply to all of its children. The current state is always the innermost one.
 #[derive(Default, Clone)]
 pub struct Fsm<T>
 where
     T: FsmIntegration<T>,
 {
-    integration: T,
-    props: Rc<RefCell<FsmProps<T>>>,
+    pub(super) integration: T,
+    pub(super) props: Rc<RefCell<FsmProps<T>>>,
 }
 
 impl<T> Fsm<T>
@@ -35,66 +105,179 @@ where
             integration,
             props: Rc::new(RefCell::new(FsmProps {
                 states: HashMap::new(),
+                order: Vec::new(),
+                initial: HashMap::new(),
+                history: HashMap::new(),
+                history_kinds: HashMap::new(),
                 current_state: None,
+                log: VecDeque::new(),
+                log_capacity: 0,
             })),
         }
     }
 
     /// Triggers a state change. Transition will only happen if stateClass is in the list
-    /// of transitions for the currentState.
+    /// of transitions for the currentState, or for any of its parents.
     /// - state - The struct of the state to change to.
-    pub fn goto(&self, state: impl State<T> + 'static) -> bool {
-        // should be private i think
-        let state_type_id = state.type_id();
+    ///
+    /// The states are exited from the innermost one up to the common parent of the current
+    /// and the new state, then entered from the common parent down to the new state
+    /// and its initial substates.
+    pub fn goto(&self, state: impl State<T> + 'static) -> Result<(), FsmError> {
+        self.goto_state(&state)
+    }
 
-        // detect transition exists
-        let allowed = {
+    // Triggers a state change to the state, which is possibly boxed
+    pub(super) fn goto_state(&self, state: &dyn State<T>) -> Result<(), FsmError> {
+        {
             let props = self.props.borrow();
-            props
-                .current_state
-                .as_ref()
-                .map(|current_state| {
-                    current_state
+
+            // detect transition exists, on the current state or any of its parents
+            if let Some(current_state) = props.current_state.as_ref() {
+                let allowed = props
+                    .path(current_state)
+                    .iter()
+                    .any(|item| item.transitions.allows(state.type_id()));
+
+                if !allowed {
+                    return Err(FsmError::TransitionNotAllowed {
+                        state: current_state.name.clone(),
+                        input: format!("{:?}", state),
+                    });
+                }
+            }
+        }
+
+        self.change(state, None, &|| {})
+    }
+
+    /// Send the event to the machine.
+    ///
+    /// The transition on the event is looked up on the current state first, then on its parents.
+    /// The first transition allowed by its guard is taken: the machine changes to its target state,
+    /// as with [goto](Fsm::goto), and its action is called after the states are exited and before
+    /// the target ones are entered. The action is not called if the target state has not been added.
+    pub fn send<E>(&self, event: E) -> Result<(), FsmError>
+    where
+        E: fmt::Debug + 'static,
+    {
+        let event_type_id = TypeId::of::<E>();
+
+        let (state_name, candidates) = {
+            let props = self.props.borrow();
+            let current_state = props.current_state.as_ref().ok_or(FsmError::NotStarted)?;
+
+            let candidates = props
+                .path(current_state)
+                .into_iter()
+                .rev()
+                .flat_map(|state_def| {
+                    let indices = state_def
                         .transitions
+                        .events()
                         .iter()
-                        .position(|item| item.type_id() == state.type_id())
-                        .unwrap_or_default()
+                        .enumerate()
+                        .filter(|(_, transition)| transition.event() == event_type_id)
+                        .map(|(index, _)| index)
+                        .collect::<Vec<_>>();
+
+                    indices.into_iter().map(move |index| (state_def.clone(), index))
                 })
-                .map(|_| true)
-                .unwrap_or_default()
+                .collect::<Vec<_>>();
+
+            (current_state.name.clone(), candidates)
         };
 
-        let mut props = self.props.borrow_mut();
+        // the props are released, so the guards and the actions are able to access the fsm
+        let transition = candidates
+            .iter()
+            .map(|(state_def, index)| &state_def.transitions.events()[*index])
+            .find(|transition| transition.allows(&event));
+
+        match transition {
+            Some(transition) => self.change(transition.target(), Some(format!("{:?}", event)), &|| {
+                transition.act(&event)
+            }),
+            None if candidates.is_empty() => Err(FsmError::TransitionNotAllowed {
+                state: state_name,
+                input: format!("{:?}", event),
+            }),
+            None => Err(FsmError::GuardRejected {
+                state: state_name,
+                input: format!("{:?}", event),
+            }),
+        }
+    }
+
+    // Change to the state, without checking the transitions
+    fn change(&self, state: &dyn State<T>, event: Option<String>, action: &dyn Fn()) -> Result<(), FsmError> {
+        let (exited, entered) = {
+            let mut props = self.props.borrow_mut();
+
+            let new_state = match props.states.get(&state.type_id()) {
+                Some(new_state) => new_state.clone(),
+                None => return Err(FsmError::UnknownState(format!("{:?}", state))),
+            };
 
-        let current_state = props.current_state.clone();
+            let target_path = props.path(&new_state);
 
-        match props.states.get_mut(&state_type_id) {
-            Some(new_state) => {
+            // the number of the common parents, which are not exited
+            let (exited, common) = match props.current_state.as_ref() {
                 // State transition
-                if current_state.is_some() {
-                    // transition allowed
-                    if allowed {
-                        // make transition
-                        self.integration.transition(new_state.clone(), current_state);
+                Some(current_state) => {
+                    let current_path = props.path(current_state);
 
-                        props.current_state = Some(new_state.clone());
-                        log::warn!("Handle integration");
+                    let mut common = current_path
+                        .iter()
+                        .zip(target_path.iter())
+                        .take_while(|(current, target)| Rc::ptr_eq(current, target))
+                        .count();
 
-                        return true;
+                    // the new state is the current one or its parent, so it is exited and entered again
+                    if common == target_path.len() {
+                        common -= 1;
                     }
 
-                    log::warn!("No transition defined from {} to {:?}", self.current_state_name(), state);
-                } else {
-                    // Initial state transition
-                    self.integration.transition(new_state.clone(), None);
-                    props.current_state = Some(new_state.clone());
-                    return true;
+                    (current_path[common..].iter().rev().cloned().collect::<Vec<_>>(), common)
+                }
+                // Initial state transition
+                None => (Vec::new(), 0),
+            };
+
+            // the history is recorded first, so the state exited and entered again resumes it
+            for state_def in exited.iter() {
+                if let Some(parent) = state_def.parent {
+                    props.history.insert(parent, state_def.type_id());
                 }
             }
-            None => panic!("Attempting to transtion to {:?}, but state has not been added.", state),
-        }
 
-        false
+            let mut entered = target_path[common..].to_vec();
+            entered.extend(props.entry_path(&new_state));
+
+            let from = props.current_state.as_ref().map(|state_def| state_def.name.clone());
+            props.current_state = entered.last().cloned();
+
+            if props.log_capacity > 0 {
+                if props.log.len() == props.log_capacity {
+                    props.log.pop_front();
+                }
+
+                let to = entered.last().map(|state_def| state_def.name.clone()).unwrap_or_default();
+                props.log.push_back(TransitionRecord {
+                    from,
+                    to,
+                    event,
+                    timestamp: SystemTime::now(),
+                });
+            }
+
+            (exited, entered)
+        };
+
+        // make transition, the props are released so the states are able to access the fsm
+        self.integration.transition(&exited, &entered, action);
+
+        Ok(())
     }
 
     /// Add a state with transitions to FSM.
@@ -102,28 +285,98 @@ where
     /// Passing the struct instead of a string reference for convinience.
     /// While setting the state will be about 4X slower, and getting about 10X, it should not be a
     /// concern unless you are going to switch state thousands of times per second.
-    pub fn add(&self, state: impl State<T> + 'static, transitions: Transitions<T>) {
-        let state_def = StateDef::new(state, transitions);
+    pub fn add(&self, state: impl State<T> + 'static, transitions: impl Into<Transitions<T>>) -> Result<(), FsmError> {
+        self.insert(StateDef::new(state, transitions.into()))
+    }
+
+    /// Add a child state of the `P` state with transitions to FSM.
+    ///
+    /// The first child added to the parent is its initial substate,
+    /// see [set_initial](Fsm::set_initial).
+    pub fn add_child<P>(
+        &self,
+        state: impl State<T> + 'static,
+        transitions: impl Into<Transitions<T>>,
+    ) -> Result<(), FsmError>
+    where
+        P: State<T> + 'static,
+    {
+        let parent = TypeId::of::<P>();
+        if !self.props.borrow().states.contains_key(&parent) {
+            return Err(FsmError::UnknownState(type_name::<P>()));
+        }
+
+        let state_def = StateDef::with_parent(state, transitions.into(), parent);
         let state_type_id = state_def.type_id();
 
+        self.insert(state_def)?;
+        self.props.borrow_mut().initial.entry(parent).or_insert(state_type_id);
+        Ok(())
+    }
+
+    /// Set the initial substate `C` of the compound state `P`
+    pub fn set_initial<P, C>(&self) -> Result<(), FsmError>
+    where
+        P: State<T> + 'static,
+        C: State<T> + 'static,
+    {
+        let (parent, child) = (TypeId::of::<P>(), TypeId::of::<C>());
+
         let mut props = self.props.borrow_mut();
-        if props.states.contains_key(&state_type_id) {
-            // TODO: Rather not have this as a runtime error, should be a macro for that.
-            // panic!("Trying to add {:?} several times. Only add states once!", state_def);
-            unimplemented!()
+        match props.states.get(&child) {
+            Some(state_def) if state_def.parent == Some(parent) => {
+                props.initial.insert(parent, child);
+                Ok(())
+            }
+            Some(state_def) => Err(FsmError::NotAChild {
+                parent: type_name::<P>(),
+                state: state_def.name.clone(),
+            }),
+            None => Err(FsmError::UnknownState(type_name::<C>())),
         }
+    }
 
+    fn insert(&self, state_def: StateDef<T>) -> Result<(), FsmError> {
+        let state_type_id = state_def.type_id();
+
+        let mut props = self.props.borrow_mut();
+        // the names identify the states in the snapshots, so they are unique as well
+        let duplicate = props.states.contains_key(&state_type_id)
+            || props.states.values().any(|other| other.name == state_def.name);
+        if duplicate {
+            return Err(FsmError::DuplicateState(state_def.name));
+        }
+
+        props.order.push(state_type_id);
         props.states.insert(state_type_id, Rc::new(state_def));
+        Ok(())
     }
 
-    /// Retrieve current state
-    pub fn current_state_name(&self) -> String {
-        // let props = self.props.borrow();
-        // props
-        //     .current_state
-        //     .as_ref()
-        //     .map(|x| format!("{:?}", x))
-        //     .unwrap_or_default()
-        unimplemented!()
+    /// Retrieve the name of the current state,
+    /// fails with [FsmError::NotStarted] if the machine has not entered any state
+    pub fn current_state_name(&self) -> Result<String, FsmError> {
+        let props = self.props.borrow();
+        props
+            .current_state
+            .as_ref()
+            .map(|state_def| state_def.name.clone())
+            .ok_or(FsmError::NotStarted)
+    }
+
+    /// Check if the machine is in the `S` state,
+    /// fails with [FsmError::NotStarted] if the machine has not entered any state.
+    ///
+    /// The compound state is current while the machine is in any of its children.
+    pub fn current_state<S>(&self) -> Result<bool, FsmError>
+    where
+        S: State<T> + 'static,
+    {
+        let props = self.props.borrow();
+        let current_state = props.current_state.as_ref().ok_or(FsmError::NotStarted)?;
+
+        Ok(props
+            .path(current_state)
+            .iter()
+            .any(|state_def| state_def.type_id() == TypeId::of::<S>()))
     }
 }
@@ -1,16 +1,62 @@
-use super::{integrations::FsmIntegration, Fsm, State};
-// use Timer;
+use std::{
+    cell::{Cell, RefCell},
+    collections::VecDeque,
+    fmt,
+    time::{Duration, Instant},
+};
+
+use super::{integrations::FsmIntegration, Fsm, FsmError, State};
+
+/// Identifies the pending transition of the [FsmController]
+pub type PendingId = u64;
+
+/// Represents the outcome of [FsmController::goto]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Goto {
+    /// The machine has changed to the state
+    Done,
+
+    /// The guard has rejected the transition, it is retried on [tick](FsmController::tick)
+    Pending(PendingId),
+}
+
+/// Describes the pending transition of the [FsmController]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PendingInfo {
+    /// The identifier of the transition
+    pub id: PendingId,
+    /// The name of the target state
+    pub state: String,
+    /// The time the transition times out at
+    pub deadline: Option<Instant>,
+}
+
+// The transition waiting for its guard
+struct PendingTransition<T>
+where
+    T: FsmIntegration<T>,
+{
+    id: PendingId,
+    state: Box<dyn State<T>>,
+    guard: Box<dyn Fn() -> bool>,
+    deadline: Option<Instant>,
+}
 
 /// Controller to inject into application
-/// 
+///
 /// Injecting FSM directly to would give access to add method, but adding states should be done during initial
 /// configuration. So provide access to FsmController instead to restrict usage to only the functionality needed after
 /// startup.
+///
+/// The transitions rejected by their guards are able to wait in the queue of the controller,
+/// the guards are retried on each [tick](FsmController::tick) in the order the transitions were queued.
 pub struct FsmController<T>
 where
     T: FsmIntegration<T>,
 {
     fsm: Fsm<T>,
+    pending: RefCell<VecDeque<PendingTransition<T>>>,
+    next_id: Cell<PendingId>,
 }
 
 impl<T> FsmController<T>
@@ -19,33 +65,139 @@ where
 {
     /// Create new FsmController
     pub fn new(fsm: Fsm<T>) -> Self {
-        Self { fsm }
+        Self {
+            fsm,
+            pending: RefCell::new(VecDeque::new()),
+            next_id: Cell::new(0),
+        }
     }
 
     // wait: Option<bool> = true
-    /// Goto to the state
+    /// Goto to the state.
+    ///
+    /// If the guard rejects the transition, it waits for the next [tick](FsmController::tick),
+    /// unless the `wait` is `false`.
     pub fn goto(
         &self,
         state: impl State<T> + 'static,
         guard: Option<Box<dyn Fn() -> bool>>,
         wait: Option<bool>,
-    ) -> bool {
+    ) -> Result<Goto, FsmError> {
         if let Some(guard) = guard {
             let allowed = guard();
             if !allowed && !wait.unwrap_or(true) {
-                return false;
+                return Err(FsmError::GuardRejected {
+                    state: self.fsm.current_state_name().unwrap_or_default(),
+                    input: format!("{:?}", state),
+                });
             } else if !allowed {
-                // FIXME: should called next tick async fashion
-                self.goto(state, Some(guard), None);
-                return true;
+                return Ok(Goto::Pending(self.defer(state, guard, None)));
             }
         }
 
-        self.fsm.goto(state)
+        self.fsm.goto(state).map(|_| Goto::Done)
+    }
+
+    /// Queue the transition to the state until the guard allows it.
+    ///
+    /// The transition is dropped on the first [tick](FsmController::tick) after the timeout.
+    pub fn defer(
+        &self,
+        state: impl State<T> + 'static,
+        guard: Box<dyn Fn() -> bool>,
+        timeout: Option<Duration>,
+    ) -> PendingId {
+        let id = self.next_id.get();
+        self.next_id.set(id + 1);
+
+        self.pending.borrow_mut().push_back(PendingTransition {
+            id,
+            state: Box::new(state),
+            guard,
+            deadline: timeout.map(|timeout| Instant::now() + timeout),
+        });
+
+        id
     }
 
-    /// Retrieve current state
-    pub fn current_state_name(&self) -> String {
+    /// Retry the guards of the pending transitions.
+    ///
+    /// Returns the transitions settled by the tick, either taken, failed or timed out.
+    /// The transitions queued by the states entered during the tick wait for the next one.
+    pub fn tick(&self) -> Vec<(PendingId, Result<(), FsmError>)> {
+        // the queue is released, so the guards and the states are able to use the controller
+        let pending = self.pending.take();
+        let now = Instant::now();
+
+        let mut settled = Vec::new();
+        let mut waiting = VecDeque::new();
+
+        for transition in pending {
+            if (transition.guard)() {
+                settled.push((transition.id, self.fsm.goto_state(transition.state.as_ref())));
+            } else if transition.deadline.map(|deadline| deadline <= now).unwrap_or_default() {
+                let error = FsmError::TimedOut(format!("{:?}", transition.state));
+                settled.push((transition.id, Err(error)));
+            } else {
+                waiting.push_back(transition);
+            }
+        }
+
+        let mut pending = self.pending.borrow_mut();
+        waiting.append(&mut pending);
+        *pending = waiting;
+
+        settled
+    }
+
+    /// Cancel the pending transition, returns `false` if it is not pending
+    pub fn cancel(&self, id: PendingId) -> bool {
+        let mut pending = self.pending.borrow_mut();
+        let len = pending.len();
+        pending.retain(|transition| transition.id != id);
+        pending.len() != len
+    }
+
+    /// Cancel all pending transitions
+    pub fn cancel_all(&self) {
+        self.pending.borrow_mut().clear();
+    }
+
+    /// Retrieve the pending transitions, in the order they are retried
+    pub fn pending(&self) -> Vec<PendingInfo> {
+        self.pending
+            .borrow()
+            .iter()
+            .map(|transition| PendingInfo {
+                id: transition.id,
+                state: format!("{:?}", transition.state),
+                deadline: transition.deadline,
+            })
+            .collect()
+    }
+
+    /// Retrieve the name of the current state, see [Fsm::current_state_name]
+    pub fn current_state_name(&self) -> Result<String, FsmError> {
         self.fsm.current_state_name()
     }
+
+    /// Check if the machine is in the `S` state, see [Fsm::current_state]
+    pub fn current_state<S>(&self) -> Result<bool, FsmError>
+    where
+        S: State<T> + 'static,
+    {
+        self.fsm.current_state::<S>()
+    }
+}
+
+impl<T> fmt::Debug for FsmController<T>
+where
+    T: FsmIntegration<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FsmController")
+            .field("current_state", &self.fsm.current_state_name().ok())
+            .field("pending", &self.pending())
+            .finish()
+    }
 }
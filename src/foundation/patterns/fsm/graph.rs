@@ -0,0 +1,230 @@
+use std::{any::TypeId, collections::HashMap, fmt::Write, rc::Rc};
+
+use super::{EventTransition, Fsm, FsmIntegration, FsmProps, State, StateDef, Typed};
+
+impl<T> Fsm<T>
+where
+    T: FsmIntegration<T>,
+{
+    /// Render the states and the transitions to the Graphviz DOT.
+    ///
+    /// The compound states are rendered as the clusters, the transitions allowed
+    /// with [goto](Fsm::goto) are dashed and the transitions on the events are labeled
+    /// with the event, the guard and the action. The current state is filled.
+    pub fn to_dot(&self) -> String {
+        let props = self.props.borrow();
+
+        let mut dot = String::from("digraph Fsm {\n");
+        write_dot_states(&props, None, 1, &mut dot);
+
+        for state_def in props.order.iter().map(|type_id| &props.states[type_id]) {
+            let name = escape_dot(&state_def.name);
+
+            for target in state_def.transitions.states() {
+                let target = escape_dot(&name_of(&props, target.as_ref()));
+                let _ = writeln!(dot, "    \"{}\" -> \"{}\" [style = dashed];", name, target);
+            }
+
+            for transition in state_def.transitions.events() {
+                let target = escape_dot(&name_of(&props, transition.target()));
+                let label = escape_dot(&label_of(transition));
+                let _ = writeln!(dot, "    \"{}\" -> \"{}\" [label = \"{}\"];", name, target, label);
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Render the states and the transitions to the Mermaid state diagram.
+    ///
+    /// The transitions on the events are labeled with the event, the guard and the action.
+    /// The current state has the `current` class.
+    ///
+    /// The names of the states are not valid Mermaid ids, as the generic arguments are kept,
+    /// so the states are declared with the generated ids `s0`, `s1` and so on.
+    pub fn to_mermaid(&self) -> String {
+        let props = self.props.borrow();
+
+        let mut ids: HashMap<TypeId, String> = props
+            .order
+            .iter()
+            .enumerate()
+            .map(|(index, type_id)| (*type_id, format!("s{}", index)))
+            .collect();
+
+        // the targets of the transitions, which have not been added
+        let mut missing = Vec::new();
+        for state_def in props.order.iter().map(|type_id| &props.states[type_id]) {
+            for target in targets(state_def) {
+                if !ids.contains_key(&target.type_id()) {
+                    let id = format!("s{}", ids.len());
+                    missing.push((id.clone(), format!("{:?}", target)));
+                    ids.insert(target.type_id(), id);
+                }
+            }
+        }
+
+        let mut mermaid = String::from("stateDiagram-v2\n");
+        write_mermaid_states(&props, &ids, None, 1, &mut mermaid);
+        for (id, name) in missing.iter() {
+            let _ = writeln!(mermaid, "    state \"{}\" as {}", escape_mermaid(name), id);
+        }
+
+        for state_def in props.order.iter().map(|type_id| &props.states[type_id]) {
+            let id = &ids[&state_def.type_id()];
+
+            for target in state_def.transitions.states() {
+                let _ = writeln!(mermaid, "    {} --> {}", id, ids[&target.type_id()]);
+            }
+
+            for transition in state_def.transitions.events() {
+                let target = &ids[&transition.target().type_id()];
+                let _ = writeln!(mermaid, "    {} --> {} : {}", id, target, label_of(transition));
+            }
+        }
+
+        if let Some(current_state) = props.current_state.as_ref() {
+            mermaid.push_str("    classDef current fill:lightblue\n");
+            let _ = writeln!(mermaid, "    class {} current", ids[&current_state.type_id()]);
+        }
+
+        mermaid
+    }
+}
+
+fn write_dot_states<T>(props: &FsmProps<T>, parent: Option<TypeId>, depth: usize, dot: &mut String)
+where
+    T: FsmIntegration<T>,
+{
+    let indent = "    ".repeat(depth);
+
+    for state_def in children(props, parent) {
+        let children = children(props, Some(state_def.type_id()));
+        let name = escape_dot(&state_def.name);
+        let filled = if is_current(props, &state_def) {
+            ", style = filled, fillcolor = lightblue"
+        } else {
+            ""
+        };
+
+        if children.is_empty() {
+            let _ = writeln!(dot, "{}\"{}\" [shape = ellipse{}];", indent, name, filled);
+            continue;
+        }
+
+        let _ = writeln!(dot, "{}subgraph \"cluster_{}\" {{", indent, name);
+        let _ = writeln!(dot, "{}    label = \"{}\";", indent, name);
+        let _ = writeln!(dot, "{}    \"{}\" [shape = box{}];", indent, name, filled);
+
+        if let Some(initial) = props.initial.get(&state_def.type_id()) {
+            let initial = escape_dot(&props.states[initial].name);
+            let _ = writeln!(dot, "{}    \"{}.initial\" [shape = point];", indent, name);
+            let _ = writeln!(dot, "{}    \"{}.initial\" -> \"{}\";", indent, name, initial);
+        }
+
+        write_dot_states(props, Some(state_def.type_id()), depth + 1, dot);
+        let _ = writeln!(dot, "{}}}", indent);
+    }
+}
+
+fn write_mermaid_states<T>(
+    props: &FsmProps<T>,
+    ids: &HashMap<TypeId, String>,
+    parent: Option<TypeId>,
+    depth: usize,
+    mermaid: &mut String,
+) where
+    T: FsmIntegration<T>,
+{
+    let indent = "    ".repeat(depth);
+
+    for state_def in children(props, parent) {
+        let id = &ids[&state_def.type_id()];
+        let _ = writeln!(mermaid, "{}state \"{}\" as {}", indent, escape_mermaid(&state_def.name), id);
+
+        if children(props, Some(state_def.type_id())).is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(mermaid, "{}state {} {{", indent, id);
+
+        if let Some(initial) = props.initial.get(&state_def.type_id()) {
+            let _ = writeln!(mermaid, "{}    [*] --> {}", indent, ids[initial]);
+        }
+
+        write_mermaid_states(props, ids, Some(state_def.type_id()), depth + 1, mermaid);
+        let _ = writeln!(mermaid, "{}}}", indent);
+    }
+}
+
+// The states with the parent, in the order they are added
+fn children<T>(props: &FsmProps<T>, parent: Option<TypeId>) -> Vec<Rc<StateDef<T>>>
+where
+    T: FsmIntegration<T>,
+{
+    props
+        .order
+        .iter()
+        .map(|type_id| &props.states[type_id])
+        .filter(|state_def| state_def.parent == parent)
+        .cloned()
+        .collect()
+}
+
+fn is_current<T>(props: &FsmProps<T>, state_def: &Rc<StateDef<T>>) -> bool
+where
+    T: FsmIntegration<T>,
+{
+    props
+        .current_state
+        .as_ref()
+        .map(|current_state| Rc::ptr_eq(current_state, state_def))
+        .unwrap_or_default()
+}
+
+// The name of the added state, the target of the transition is possibly not added
+fn name_of<T>(props: &FsmProps<T>, state: &dyn State<T>) -> String
+where
+    T: FsmIntegration<T>,
+{
+    props
+        .states
+        .get(&state.type_id())
+        .map(|state_def| state_def.name.clone())
+        .unwrap_or_else(|| format!("{:?}", state))
+}
+
+// The targets of the transitions of the state
+fn targets<T>(state_def: &StateDef<T>) -> Vec<&dyn State<T>>
+where
+    T: FsmIntegration<T>,
+{
+    let states = state_def.transitions.states().iter().map(|target| target.as_ref());
+    let events = state_def.transitions.events().iter().map(|transition| transition.target());
+    states.chain(events).collect()
+}
+
+// Escape the text quoted in the DOT
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+// Escape the text quoted in the Mermaid, which has no escape sequences but the entity codes
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn label_of<T>(transition: &EventTransition<T>) -> String
+where
+    T: FsmIntegration<T>,
+{
+    let mut label = transition.name().to_string();
+    if transition.has_guard() {
+        label.push_str(" [guard]");
+    }
+    if transition.has_action() {
+        label.push_str(" / action");
+    }
+    label
+}
@@ -0,0 +1,79 @@
+use std::{any::TypeId, time::SystemTime};
+
+use super::{state_def::type_name, Fsm, FsmError, FsmIntegration, State};
+
+/// Represents the history of the compound state
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum History {
+    /// Resume the substate the compound state was last in,
+    /// its own substates are entered as usual
+    Shallow,
+
+    /// Resume the innermost substate the compound state was last in
+    Deep,
+}
+
+/// Represents the transition recorded to the log of the [Fsm]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransitionRecord {
+    /// The name of the state the machine was in
+    pub from: Option<String>,
+    /// The name of the state the machine has entered
+    pub to: String,
+    /// The event of the transition, if it was sent
+    pub event: Option<String>,
+    /// The time of the transition
+    pub timestamp: SystemTime,
+}
+
+impl<T> Fsm<T>
+where
+    T: FsmIntegration<T>,
+{
+    /// Set the history of the compound state `P`.
+    ///
+    /// Entering the compound state resumes the substate it was last in,
+    /// instead of its initial substate.
+    pub fn set_history<P>(&self, history: History) -> Result<(), FsmError>
+    where
+        P: State<T> + 'static,
+    {
+        let parent = TypeId::of::<P>();
+
+        let mut props = self.props.borrow_mut();
+        if !props.states.contains_key(&parent) {
+            return Err(FsmError::UnknownState(type_name::<P>()));
+        }
+
+        props.history_kinds.insert(parent, history);
+        Ok(())
+    }
+
+    /// Set the capacity of the transition log, the oldest transitions are dropped.
+    ///
+    /// The log is disabled with the zero capacity, which is the default.
+    pub fn set_log_capacity(&self, capacity: usize) {
+        let mut props = self.props.borrow_mut();
+
+        while props.log.len() > capacity {
+            props.log.pop_front();
+        }
+
+        props.log_capacity = capacity;
+    }
+
+    /// Retrieve the transition log, from the oldest transition
+    pub fn transition_log(&self) -> Vec<TransitionRecord> {
+        self.props.borrow().log.iter().cloned().collect()
+    }
+
+    /// Retrieve the last transition of the log
+    pub fn last_transition(&self) -> Option<TransitionRecord> {
+        self.props.borrow().log.back().cloned()
+    }
+
+    /// Clear the transition log
+    pub fn clear_transition_log(&self) {
+        self.props.borrow_mut().log.clear();
+    }
+}
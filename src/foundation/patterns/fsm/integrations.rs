@@ -1,15 +1,22 @@
-use std::rc::Rc;
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    fmt,
+    rc::Rc,
+};
 
-use super::StateDef;
+use crate::prelude::{Command, Facade, Interest, Lock, Shared};
 
-// pub mod command;
-
-// pub mod injector;
+use super::{State, StateDef, Typed};
 
 /// Defines finite state machine integration functionality
 pub trait FsmIntegration<T: FsmIntegration<T>>: Clone {
     /// Makes a transition from one state to another
-    fn transition(&self, new_state: Rc<StateDef<T>>, old_state: Option<Rc<StateDef<T>>>) -> bool;
+    ///
+    /// - exited - The states exited, from the innermost one outwards.
+    /// - entered - The states entered, from the outermost one inwards.
+    /// - action - The action of the transition, called after the exited states and before the entered ones.
+    fn transition(&self, exited: &[Rc<StateDef<T>>], entered: &[Rc<StateDef<T>>], action: &dyn Fn()) -> bool;
 }
 
 /// Represents callback integration
@@ -18,16 +25,214 @@ pub struct CallbackIntegration;
 
 impl CallbackIntegration {
     /// Create new callback integration
-    pub fn new() {}
+    pub fn new() -> Self {
+        Self
+    }
 }
 
 impl FsmIntegration<Self> for CallbackIntegration {
-    fn transition(&self, new_state: Rc<StateDef<Self>>, old_state: Option<Rc<StateDef<Self>>>) -> bool {
-        if let Some(ref old_state) = old_state {
+    fn transition(&self, exited: &[Rc<StateDef<Self>>], entered: &[Rc<StateDef<Self>>], action: &dyn Fn()) -> bool {
+        for old_state in exited {
             old_state.state.exit(self)
         }
 
-        new_state.state.enter(self);
+        action();
+
+        for new_state in entered {
+            new_state.state.enter(self);
+        }
+
+        true
+    }
+}
+
+/// Represents the state change, the body of the notifications sent by the [FacadeIntegration]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateChange {
+    /// The names of the states exited, from the innermost one outwards
+    pub exited: Vec<String>,
+    /// The names of the states entered, from the outermost one inwards
+    pub entered: Vec<String>,
+}
+
+// The notifications and the commands of the state
+struct StateConfig<Body> {
+    entered: Option<Interest>,
+    exited: Option<Interest>,
+    commands: Vec<(Interest, Shared<dyn Command<Body>>)>,
+}
+
+impl<Body> Default for StateConfig<Body> {
+    fn default() -> Self {
+        Self {
+            entered: None,
+            exited: None,
+            commands: Vec::new(),
+        }
+    }
+}
+
+impl<Body> Clone for StateConfig<Body> {
+    fn clone(&self) -> Self {
+        Self {
+            entered: self.entered,
+            exited: self.exited,
+            commands: self.commands.clone(),
+        }
+    }
+}
+
+/// Represents the integration driving the [Facade], the StateMachine utility of PureMVC.
+///
+/// Entering and exiting the state sends the configured Interest with the [StateChange]
+/// as the body, and the commands of the state are registered while the machine is in it.
+///
+/// On the transition, each exited state is exited, its exit Interest is sent and its commands
+/// are removed. Then the commands of each entered state are registered, it is entered and its
+/// enter Interest is sent. Finally the change Interest is sent.
+///
+/// The command of the state is not registered if the Interest already has a command,
+/// so the commands of the application are neither replaced nor removed by the machine.
+pub struct FacadeIntegration<Body>
+where
+    Body: From<StateChange> + fmt::Debug + 'static,
+{
+    facade: Shared<dyn Facade<Body>>,
+    changed: Option<Interest>,
+    states: HashMap<TypeId, StateConfig<Body>>,
+
+    // The interests of the commands registered by the integration
+    registered: Shared<Lock<HashSet<Interest>>>,
+}
+
+impl<Body> FacadeIntegration<Body>
+where
+    Body: From<StateChange> + fmt::Debug + 'static,
+{
+    /// Create new facade integration
+    pub fn new(facade: Shared<dyn Facade<Body>>) -> Self {
+        Self {
+            facade,
+            changed: None,
+            states: HashMap::new(),
+            registered: Shared::new(Lock::new(HashSet::new())),
+        }
+    }
+
+    /// Send the Interest on every transition
+    pub fn on_change(mut self, interest: Interest) -> Self {
+        self.changed = Some(interest);
+        self
+    }
+
+    /// Send the Interest on entering the `S` state
+    pub fn on_enter<S>(mut self, interest: Interest) -> Self
+    where
+        S: State<Self> + 'static,
+    {
+        self.states.entry(TypeId::of::<S>()).or_default().entered = Some(interest);
+        self
+    }
+
+    /// Send the Interest on exiting the `S` state
+    pub fn on_exit<S>(mut self, interest: Interest) -> Self
+    where
+        S: State<Self> + 'static,
+    {
+        self.states.entry(TypeId::of::<S>()).or_default().exited = Some(interest);
+        self
+    }
+
+    /// Register the command on entering the `S` state, and remove it on exiting
+    pub fn with_command<S>(mut self, interest: Interest, command: Shared<dyn Command<Body>>) -> Self
+    where
+        S: State<Self> + 'static,
+    {
+        let config = self.states.entry(TypeId::of::<S>()).or_default();
+        config.commands.push((interest, command));
+        self
+    }
+}
+
+impl<Body> Clone for FacadeIntegration<Body>
+where
+    Body: From<StateChange> + fmt::Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            facade: self.facade.clone(),
+            changed: self.changed,
+            states: self.states.clone(),
+            registered: self.registered.clone(),
+        }
+    }
+}
+
+impl<Body> fmt::Debug for FacadeIntegration<Body>
+where
+    Body: From<StateChange> + fmt::Debug + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FacadeIntegration")
+            .field("changed", &self.changed)
+            .field("states", &self.states.len())
+            .finish()
+    }
+}
+
+impl<Body> FsmIntegration<Self> for FacadeIntegration<Body>
+where
+    Body: From<StateChange> + fmt::Debug + 'static,
+{
+    fn transition(&self, exited: &[Rc<StateDef<Self>>], entered: &[Rc<StateDef<Self>>], action: &dyn Fn()) -> bool {
+        let change = StateChange {
+            exited: exited.iter().map(|state_def| state_def.name.clone()).collect(),
+            entered: entered.iter().map(|state_def| state_def.name.clone()).collect(),
+        };
+
+        for old_state in exited {
+            old_state.state.exit(self);
+
+            if let Some(config) = self.states.get(&old_state.type_id()) {
+                if let Some(interest) = config.exited {
+                    self.facade.send(interest, Some(change.clone().into()));
+                }
+
+                for (interest, _) in config.commands.iter() {
+                    if self.registered.borrow_mut().remove(interest) {
+                        self.facade.remove_command(interest);
+                    }
+                }
+            }
+        }
+
+        action();
+
+        for new_state in entered {
+            let config = self.states.get(&new_state.type_id());
+
+            if let Some(config) = config {
+                for (interest, command) in config.commands.iter() {
+                    if self.facade.has_command(interest) {
+                        log::warn!("Skip Command of {} [FacadeIntegration] {:?} has a command", new_state.name, interest);
+                        continue;
+                    }
+
+                    self.registered.borrow_mut().insert(*interest);
+                    self.facade.register_command(*interest, command.clone());
+                }
+            }
+
+            new_state.state.enter(self);
+
+            if let Some(interest) = config.and_then(|config| config.entered) {
+                self.facade.send(interest, Some(change.clone().into()));
+            }
+        }
+
+        if let Some(interest) = self.changed {
+            self.facade.send(interest, Some(change.into()));
+        }
 
         true
     }
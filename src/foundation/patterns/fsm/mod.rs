@@ -16,78 +16,148 @@
 //! input *buttonpressed*, and back again to *On* for the same input.
 //!
 //! > **Please note:** In automata theory an automaton reacts on inputs and produces outputs. There, the terms input and 
-//! output are usually used for symbols which belong to an alphabet. Modern state machines use an extended definition 
-//! of inputs and outputs. Inputs can be events like a button click or a time trigger while outputs are actions like 
-//! an operation call or a variable assignment.
+//! > output are usually used for symbols which belong to an alphabet. Modern state machines use an extended definition 
+//! > of inputs and outputs. Inputs can be events like a button click or a time trigger while outputs are actions like 
+//! > an operation call or a variable assignment.
 //!
 //! In the following, we will extend the simple switch example to explain the differences between Mealy and Moore machines 
 //! as well as Harel statecharts and UML state machines.
+//!
+//! ## Hierarchical states
+//!
+//! As in Harel statecharts, the states are able to be nested. A compound state is entered
+//! together with its initial substate, and the transitions declared on the compound state
+//! apply to all of its children. On a transition the states are exited from the innermost
+//! one up to the common parent of the current and the new state, then entered from
+//! the common parent down to the new state.
+//!
+//! The compound state with the [History] resumes the substate it was last in, instead of its
+//! initial substate. The transitions are able to be recorded to the bounded log, see
+//! [set_log_capacity](Fsm::set_log_capacity).
+//!
+//! ## Events
+//!
+//! As in Mealy machines, the transitions are able to be keyed by the type of the event,
+//! with the optional guard and action:
+//!
+//! ```ignore
+//! fsm.add(Off, on(ButtonPressed).goto(On));
+//! fsm.add(On, on(ButtonPressed).when(|_| !locked()).action(|_| beep()).goto(Off));
+//!
+//! fsm.send(ButtonPressed)?;
+//! ```
+//!
+//! The event is handled by the current state or by the nearest of its parents,
+//! and the event with no matching transition results in [FsmError].
+//!
+//! ## Declaration
+//!
+//! The machine with the plain states is able to be declared with the `fsm!` macro
+//! of the `ruex-macro` crate, which checks the states at compile time:
+//!
+//! ```ignore
+//! fsm! {
+//!     pub fn loader {
+//!         Idle => [Loading],
+//!         Loading => [Ready, Failed],
+//!         Ready,
+//!         Failed => [Idle],
+//!     }
+//! }
+//!
+//! let fsm = loader(CallbackIntegration::new());
+//! ```
 
 use std::any::TypeId;
 
+#[allow(clippy::module_inception)]
 mod fsm;
 pub use fsm::*;
 
+mod error;
+pub use error::*;
+
 mod fsm_controller;
 pub use fsm_controller::*;
 
+mod graph;
+
+mod history;
+pub use history::*;
+
 mod integrations;
 pub use integrations::*;
 
+mod snapshot;
+pub use snapshot::*;
+
 mod state_def;
 pub use state_def::*;
 
-///! Defines tipe_id functionality
+mod transitions;
+pub use transitions::*;
+
+/// Defines tipe_id functionality
 pub trait Typed {
-    ///! Retrieve TypeId
+    /// Retrieve TypeId
     fn type_id(&self) -> TypeId;
 }
 
-///! Defines Factory Method functionality
+/// Defines Factory Method functionality
 pub trait FactoryMethod<T> {
-    ///! Create instance from Factory
+    /// Create instance from Factory
     fn create(&self) -> T;
 }
 
-///! State's holder
-pub type Transitions<T> = Vec<Box<dyn State<T>>>;
-
-// todo should contain PartialEq
-// pub fn contains(&self, x: &T) -> bool
-// or fn any<F>(&mut self, f: F) -> bool
-
-///! Defines State functionality for finite state machine
+/// Defines State functionality for finite state machine
 #[allow(unused_variables)]
 pub trait State<T>: std::fmt::Debug + Typed
 where
     T: FsmIntegration<T>,
 {
-    ///! Enter to state
+    /// Enter to state
     fn enter(&self, target: &T) {}
 
-    ///! Exit from state
+    /// Exit from state
     fn exit(&self, target: &T) {}
+
+    /// Save the data of the state to the [FsmSnapshot]
+    fn save(&self) -> Option<String> {
+        None
+    }
+
+    /// Load the data of the state from the [FsmSnapshot]
+    fn load(&self, data: &str) {}
 }
 
 #[cfg(test)]
 mod tests {
-    use std::{any::TypeId, rc::Rc};
+    use std::{
+        any::TypeId,
+        cell::{Cell, RefCell},
+        rc::Rc,
+        time::Duration,
+    };
 
     use super::{
-        integrations::{CallbackIntegration, FsmIntegration},
-        Fsm, FsmController, State, StateDef, Typed,
+        integrations::{CallbackIntegration, FacadeIntegration, FsmIntegration, StateChange},
+        on, Fsm, FsmController, FsmError, FsmSnapshot, Goto, History, State, StateDef, Transitions, Typed,
     };
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
 
-    // pub entered: bool,
     #[derive(Default, Debug)]
-    struct MockCallbackState;
+    struct MockCallbackState {
+        entered: Rc<Cell<bool>>,
+    }
 
     impl State<CallbackIntegration> for MockCallbackState {
         fn enter(&self, _target: &CallbackIntegration) {
-            //! self.entered = true;
+            self.entered.set(true);
         }
 
-        fn exit(&self, _target: &CallbackIntegration) {}
+        fn exit(&self, _target: &CallbackIntegration) {
+            self.entered.set(false);
+        }
     }
 
     impl Typed for MockCallbackState {
@@ -97,19 +167,10 @@ mod tests {
     }
 
     // A unit struct
-    // pub entered: bool,
     #[derive(Default, Debug)]
     struct MockInjectorStateB;
 
-    impl State<MockIntegration> for MockInjectorStateB {
-        fn enter(&self, _target: &MockIntegration) {
-            //! self.entered = true;
-        }
-
-        fn exit(&self, _target: &MockIntegration) {
-            //! self.entered = false;
-        }
-    }
+    impl State<MockIntegration> for MockInjectorStateB {}
 
     impl Typed for MockInjectorStateB {
         fn type_id(&self) -> TypeId {
@@ -117,19 +178,10 @@ mod tests {
         }
     }
 
-    // pub entered: bool,
     #[derive(Default, Debug)]
     struct MockInjectorState;
 
-    impl State<MockIntegration> for MockInjectorState {
-        fn enter(&self, _target: &MockIntegration) {
-            //! self.entered = true;
-        }
-
-        fn exit(&self, _target: &MockIntegration) {
-            //! self.entered = false;
-        }
-    }
+    impl State<MockIntegration> for MockInjectorState {}
 
     impl Typed for MockInjectorState {
         fn type_id(&self) -> TypeId {
@@ -137,16 +189,49 @@ mod tests {
         }
     }
 
+    // The states named like the ones above
+    mod other {
+        use std::{any::TypeId, marker::PhantomData};
+
+        use super::{MockIntegration, State, Typed};
+
+        #[derive(Default, Debug)]
+        pub struct MockInjectorState;
+
+        impl State<MockIntegration> for MockInjectorState {}
+
+        impl Typed for MockInjectorState {
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<Self>()
+            }
+        }
+
+        #[derive(Default, Debug)]
+        pub struct Wrapper<S>(pub PhantomData<S>);
+
+        impl<S: std::fmt::Debug + 'static> State<MockIntegration> for Wrapper<S> {}
+
+        impl<S: 'static> Typed for Wrapper<S> {
+            fn type_id(&self) -> TypeId {
+                TypeId::of::<Self>()
+            }
+        }
+    }
+
     #[derive(Default, Debug, Clone)]
     struct MockIntegration;
 
     impl FsmIntegration<Self> for MockIntegration {
-        fn transition(&self, new_state: Rc<StateDef<Self>>, old_state: Option<Rc<StateDef<Self>>>) -> bool {
-            if let Some(ref old_state) = old_state {
+        fn transition(&self, exited: &[Rc<StateDef<Self>>], entered: &[Rc<StateDef<Self>>], action: &dyn Fn()) -> bool {
+            for old_state in exited {
                 old_state.state.exit(self);
             }
 
-            new_state.state.enter(self);
+            action();
+
+            for new_state in entered {
+                new_state.state.enter(self);
+            }
 
             true
         }
@@ -154,82 +239,674 @@ mod tests {
 
     // should enter initial state
     #[test]
-    fn should_enter_initial_state() {
-        let fsm = Fsm::new(MockIntegration::default());
+    fn should_enter_initial_state() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
 
         // Seems controller should work with Rc<FSM>
         let controller = FsmController::new(fsm.clone());
 
-        fsm.add(MockInjectorState, vec![]);
+        fsm.add(MockInjectorState, vec![])?;
+        assert_eq!(controller.current_state_name(), Err(FsmError::NotStarted));
+        assert_eq!(controller.current_state::<MockInjectorState>(), Err(FsmError::NotStarted));
 
-        controller.goto(MockInjectorState, None, None);
-        // MockInjectorState entered should be true;
-        // assert_eq!(2 + 2, 4);
+        controller.goto(MockInjectorState, None, None)?;
+        assert!(controller.current_state::<MockInjectorState>()?);
+        assert_eq!(controller.current_state_name()?, "MockInjectorState");
+        Ok(())
     }
 
     // should not allow entering state if transition not added
     #[test]
-    fn should_not_allow_entering_state_if_transition_not_added() {
-        let fsm = Fsm::new(MockIntegration::default());
+    fn should_not_allow_entering_state_if_transition_not_added() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
 
-        let _controller = FsmController::new(fsm.clone());
+        let controller = FsmController::new(fsm.clone());
 
-        fsm.add(MockInjectorState, vec![]);
+        fsm.add(MockInjectorState, vec![])?;
 
-        // controller.goto(MockInjectorStateB)
-        // Attempting to transtion to MockInjectorStateB, but state has not been added 
+        assert_eq!(
+            controller.goto(MockInjectorStateB, None, None),
+            Err(FsmError::UnknownState("MockInjectorStateB".into()))
+        );
+        Ok(())
     }
 
     // should only allow adding state once
     #[test]
-    fn should_only_allow_adding_state_once() {
-        let fsm = Fsm::new(MockIntegration::default());
+    fn should_only_allow_adding_state_once() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
 
         let _controller = FsmController::new(fsm.clone());
-        fsm.add(MockInjectorState, vec![]);
+        fsm.add(MockInjectorState, vec![])?;
 
-        // fsm.add.bind(MockInjectorState, vec![])
-        // Trying to add MockInjectorState several times. Only add states once!
+        assert_eq!(
+            fsm.add(MockInjectorState, vec![]),
+            Err(FsmError::DuplicateState("MockInjectorState".into()))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn should_reject_states_with_the_same_name() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
+
+        fsm.add(MockInjectorState, vec![])?;
+        assert_eq!(
+            fsm.add(other::MockInjectorState, vec![]),
+            Err(FsmError::DuplicateState("MockInjectorState".into()))
+        );
+
+        // the generic arguments are kept in the name
+        fsm.add(other::Wrapper::<MockInjectorState>::default(), vec![])?;
+        fsm.add(other::Wrapper::<MockInjectorStateB>::default(), vec![])?;
+        fsm.goto(other::Wrapper::<MockInjectorStateB>::default())?;
+        assert_eq!(fsm.current_state_name()?, "Wrapper<MockInjectorStateB>");
+        Ok(())
     }
 
     // should not allow entering state if transition not defined
     #[test]
-    fn should_not_allow_entering_state_if_transition_not_defined() {
-        let fsm = Fsm::new(MockIntegration::default());
+    fn should_not_allow_entering_state_if_transition_not_defined() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
 
         let controller = FsmController::new(fsm.clone());
 
-        fsm.add(MockInjectorState, vec![]);
-        fsm.add(MockInjectorStateB, vec![]);
-
-        controller.goto(MockInjectorState, None, None);
-        controller.goto(MockInjectorStateB, None, None);
-        // MockInjectorStateB entered should be false;
+        fsm.add(MockInjectorState, vec![])?;
+        fsm.add(MockInjectorStateB, vec![])?;
+
+        controller.goto(MockInjectorState, None, None)?;
+        assert_eq!(
+            controller.goto(MockInjectorStateB, None, None),
+            Err(FsmError::TransitionNotAllowed {
+                state: "MockInjectorState".into(),
+                input: "MockInjectorStateB".into()
+            })
+        );
+        assert!(!controller.current_state::<MockInjectorStateB>()?);
+        Ok(())
     }
 
     // should enter defined transition
     #[test]
-    fn should_enter_defined_transition() {
-        let fsm = Fsm::new(MockIntegration::default());
+    fn should_enter_defined_transition() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
         let controller = FsmController::new(fsm.clone());
-        fsm.add(MockInjectorState, vec![Box::new(MockInjectorStateB::default())]);
-        fsm.add(MockInjectorStateB, vec![]);
-
-        controller.goto(MockInjectorState, None, None);
-        controller.goto(MockInjectorStateB, None, None);
-        // MockInjectorStateB entered should be true
-        // MockInjectorState entered should be false
+        fsm.add(MockInjectorState, Transitions::new().allow(MockInjectorStateB))?;
+        fsm.add(MockInjectorStateB, vec![])?;
+
+        controller.goto(MockInjectorState, None, None)?;
+        controller.goto(MockInjectorStateB, None, None)?;
+        assert!(controller.current_state::<MockInjectorStateB>()?);
+        assert!(!controller.current_state::<MockInjectorState>()?);
+        Ok(())
     }
 
     // should call enter on states when using callback integration
     #[test]
-    fn should_call_enter_on_states_when_using_callback_integration() {
-        let fsm = Fsm::new(CallbackIntegration::default());
+    fn should_call_enter_on_states_when_using_callback_integration() -> Result<(), FsmError> {
+        let fsm = Fsm::new(CallbackIntegration);
+
+        let controller = FsmController::new(fsm.clone());
+        let state = MockCallbackState::default();
+        let entered = state.entered.clone();
+        fsm.add(state, vec![])?;
 
+        controller.goto(MockCallbackState::default(), None, None)?;
+        assert!(entered.get());
+        Ok(())
+    }
+
+    // should retry the guards of the pending transitions on tick
+    #[test]
+    fn should_retry_pending_transitions_on_tick() -> Result<(), FsmError> {
+        let fsm = Fsm::new(MockIntegration);
         let controller = FsmController::new(fsm.clone());
-        fsm.add(MockCallbackState, vec![]);
+        fsm.add(MockInjectorState, Transitions::new().allow(MockInjectorStateB))?;
+        fsm.add(MockInjectorStateB, Transitions::new().allow(MockInjectorState))?;
+        controller.goto(MockInjectorState, None, None)?;
+
+        let ready = Rc::new(Cell::new(false));
+        let guard = {
+            let ready = ready.clone();
+            Box::new(move || ready.get())
+        };
+
+        let id = match controller.goto(MockInjectorStateB, Some(guard), None)? {
+            Goto::Pending(id) => id,
+            Goto::Done => panic!("the guard should reject the transition"),
+        };
+
+        assert!(controller.tick().is_empty());
+        assert_eq!(controller.pending()[0].id, id);
+        assert_eq!(controller.pending()[0].state, "MockInjectorStateB");
+
+        ready.set(true);
+        assert_eq!(controller.tick(), vec![(id, Ok(()))]);
+        assert!(controller.current_state::<MockInjectorStateB>()?);
+        assert!(controller.pending().is_empty());
+
+        // the transition times out or is cancelled
+        let timed_out = controller.defer(MockInjectorState, Box::new(|| false), Some(Duration::ZERO));
+        let cancelled = controller.defer(MockInjectorState, Box::new(|| false), None);
+
+        assert_eq!(
+            controller.tick(),
+            vec![(timed_out, Err(FsmError::TimedOut("MockInjectorState".into())))]
+        );
+        assert!(controller.cancel(cancelled));
+        assert!(!controller.cancel(cancelled));
+        assert!(controller.pending().is_empty());
+        Ok(())
+    }
+
+    // Records the names of the exited and entered states
+    #[derive(Default, Debug, Clone)]
+    struct RecordingIntegration {
+        log: Rc<RefCell<Vec<String>>>,
+    }
+
+    impl RecordingIntegration {
+        fn take(&self) -> Vec<String> {
+            self.log.take()
+        }
+    }
+
+    impl FsmIntegration<Self> for RecordingIntegration {
+        fn transition(&self, exited: &[Rc<StateDef<Self>>], entered: &[Rc<StateDef<Self>>], action: &dyn Fn()) -> bool {
+            self.log.borrow_mut().extend(exited.iter().map(|state| format!("exit {:?}", state.state)));
+            action();
+            self.log.borrow_mut().extend(entered.iter().map(|state| format!("enter {:?}", state.state)));
+            true
+        }
+    }
+
+    macro_rules! recording_states {
+        ($($state:ident),*) => {
+            $(
+                #[derive(Default, Debug)]
+                struct $state;
+
+                impl State<RecordingIntegration> for $state {}
+
+                impl Typed for $state {
+                    fn type_id(&self) -> TypeId {
+                        TypeId::of::<Self>()
+                    }
+                }
+            )*
+        };
+    }
+
+    recording_states!(Off, Active, Idle, Running, Lost);
+
+    // should cascade enter and exit through nested states
+    #[test]
+    fn should_cascade_enter_and_exit_through_nested_states() -> Result<(), FsmError> {
+        let integration = RecordingIntegration::default();
+        let fsm = Fsm::new(integration.clone());
+
+        fsm.add(Off, Transitions::new().allow(Active))?;
+        fsm.add(Active, Transitions::new().allow(Off))?;
+        fsm.add_child::<Active>(Idle, Transitions::new().allow(Running))?;
+        fsm.add_child::<Active>(Running, Transitions::new().allow(Idle))?;
+        assert_eq!(
+            fsm.set_initial::<Off, Idle>(),
+            Err(FsmError::NotAChild {
+                parent: "Off".into(),
+                state: "Idle".into()
+            })
+        );
+
+        fsm.goto(Off)?;
+        assert_eq!(integration.take(), vec!["enter Off"]);
+
+        // the compound state is entered with its initial substate
+        fsm.goto(Active)?;
+        assert_eq!(integration.take(), vec!["exit Off", "enter Active", "enter Idle"]);
+
+        // the common parent is neither exited nor entered
+        fsm.goto(Running)?;
+        assert_eq!(integration.take(), vec!["exit Idle", "enter Running"]);
+        assert!(fsm.current_state::<Active>()? && fsm.current_state::<Running>()?);
+
+        // the transition of the parent applies to its children
+        fsm.goto(Off)?;
+        assert_eq!(integration.take(), vec!["exit Running", "exit Active", "enter Off"]);
+
+        assert!(fsm.goto(Running).is_err());
+        assert!(integration.take().is_empty());
+        Ok(())
+    }
+
+    #[derive(Debug)]
+    struct ButtonPressed;
+
+    #[derive(Debug)]
+    struct Start(u32);
+
+    // should move the machine on the sent events
+    #[test]
+    fn should_move_machine_on_sent_events() -> Result<(), FsmError> {
+        let integration = RecordingIntegration::default();
+        let fsm = Fsm::new(integration.clone());
+
+        let log = integration.log.clone();
+        let lost = integration.log.clone();
+        fsm.add(
+            Off,
+            on(ButtonPressed).goto(Active).and(
+                on(Start(0))
+                    .action(move |start: &Start| lost.borrow_mut().push(format!("lose {}", start.0)))
+                    .goto(Lost),
+            ),
+        )?;
+        fsm.add(Active, on(ButtonPressed).goto(Off))?;
+        fsm.add_child::<Active>(Idle, Transitions::new().allow(Running))?;
+        fsm.add_child::<Active>(
+            Running,
+            on(Start(0)).when(|start: &Start| start.0 > 1).goto(Idle).and(
+                on(Start(0))
+                    .action(move |start: &Start| log.borrow_mut().push(format!("start {}", start.0)))
+                    .goto(Running),
+            ),
+        )?;
+
+        assert_eq!(fsm.send(ButtonPressed), Err(FsmError::NotStarted));
+
+        fsm.goto(Off)?;
+        integration.take();
+
+        assert_eq!(fsm.send(ButtonPressed), Ok(()));
+        assert_eq!(integration.take(), vec!["exit Off", "enter Active", "enter Idle"]);
+
+        assert_eq!(
+            fsm.send(Start(1)),
+            Err(FsmError::TransitionNotAllowed {
+                state: "Idle".into(),
+                input: "Start(1)".into()
+            })
+        );
+
+        fsm.goto(Running)?;
+        integration.take();
+
+        // the first transition allowed by its guard is taken, the action is called between the exit and the enter
+        assert_eq!(fsm.send(Start(1)), Ok(()));
+        assert_eq!(integration.take(), vec!["exit Running", "start 1", "enter Running"]);
+
+        assert_eq!(fsm.send(Start(2)), Ok(()));
+        assert_eq!(integration.take(), vec!["exit Running", "enter Idle"]);
+
+        // the transition of the parent applies to its children
+        assert_eq!(fsm.send(ButtonPressed), Ok(()));
+        assert_eq!(integration.take(), vec!["exit Idle", "exit Active", "enter Off"]);
+
+        // the action is not called if the target state has not been added
+        assert_eq!(fsm.send(Start(3)), Err(FsmError::UnknownState("Lost".into())));
+        assert!(integration.take().is_empty());
+        assert!(fsm.current_state::<Off>()?);
+        Ok(())
+    }
+
+    // should render the states and the transitions
+    #[test]
+    fn should_render_states_and_transitions() -> Result<(), FsmError> {
+        let fsm = Fsm::new(RecordingIntegration::default());
+
+        fsm.add(Off, on(ButtonPressed).goto(Active))?;
+        fsm.add(Active, on(ButtonPressed).goto(Off))?;
+        fsm.add_child::<Active>(Idle, Transitions::new().allow(Running))?;
+        fsm.add_child::<Active>(Running, on(Start(0)).when(|start: &Start| start.0 > 1).goto(Idle))?;
+        fsm.goto(Off)?;
+        fsm.send(ButtonPressed)?;
+
+        assert_eq!(
+            fsm.to_dot(),
+            [
+                "digraph Fsm {",
+                "    \"Off\" [shape = ellipse];",
+                "    subgraph \"cluster_Active\" {",
+                "        label = \"Active\";",
+                "        \"Active\" [shape = box];",
+                "        \"Active.initial\" [shape = point];",
+                "        \"Active.initial\" -> \"Idle\";",
+                "        \"Idle\" [shape = ellipse, style = filled, fillcolor = lightblue];",
+                "        \"Running\" [shape = ellipse];",
+                "    }",
+                "    \"Off\" -> \"Active\" [label = \"ButtonPressed\"];",
+                "    \"Active\" -> \"Off\" [label = \"ButtonPressed\"];",
+                "    \"Idle\" -> \"Running\" [style = dashed];",
+                "    \"Running\" -> \"Idle\" [label = \"Start [guard]\"];",
+                "}\n",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            fsm.to_mermaid(),
+            [
+                "stateDiagram-v2",
+                "    state \"Off\" as s0",
+                "    state \"Active\" as s1",
+                "    state s1 {",
+                "        [*] --> s2",
+                "        state \"Idle\" as s2",
+                "        state \"Running\" as s3",
+                "    }",
+                "    s0 --> s1 : ButtonPressed",
+                "    s1 --> s0 : ButtonPressed",
+                "    s2 --> s3",
+                "    s3 --> s2 : Start [guard]",
+                "    classDef current fill:lightblue",
+                "    class s2 current\n",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+
+    // The state named with its generic arguments
+    #[derive(Default, Debug)]
+    struct Pair<A, B>(A, B);
+
+    impl<A, B> State<RecordingIntegration> for Pair<A, B>
+    where
+        A: std::fmt::Debug + 'static,
+        B: std::fmt::Debug + 'static,
+    {
+    }
+
+    impl<A: 'static, B: 'static> Typed for Pair<A, B> {
+        fn type_id(&self) -> TypeId {
+            TypeId::of::<Self>()
+        }
+    }
+
+    // The state which is never added, its debug representation is quoted
+    struct Named(&'static str);
+
+    impl std::fmt::Debug for Named {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Named({:?})", self.0)
+        }
+    }
+
+    impl State<RecordingIntegration> for Named {}
+
+    impl Typed for Named {
+        fn type_id(&self) -> TypeId {
+            TypeId::of::<Self>()
+        }
+    }
+
+    // should render the names of the states which are not valid ids
+    #[test]
+    fn should_render_generic_states() -> Result<(), FsmError> {
+        let fsm = Fsm::new(RecordingIntegration::default());
+
+        fsm.add(Off, Transitions::new().allow(Pair(Off, Idle)))?;
+        fsm.add(Pair(Off, Idle), on(ButtonPressed).goto(Named("a\\b")))?;
+        fsm.goto(Pair(Off, Idle))?;
+
+        assert_eq!(
+            fsm.to_dot(),
+            [
+                "digraph Fsm {",
+                "    \"Off\" [shape = ellipse];",
+                "    \"Pair<Off, Idle>\" [shape = ellipse, style = filled, fillcolor = lightblue];",
+                "    \"Off\" -> \"Pair<Off, Idle>\" [style = dashed];",
+                "    \"Pair<Off, Idle>\" -> \"Named(\\\"a\\\\\\\\b\\\")\" [label = \"ButtonPressed\"];",
+                "}\n",
+            ]
+            .join("\n")
+        );
+
+        assert_eq!(
+            fsm.to_mermaid(),
+            [
+                "stateDiagram-v2",
+                "    state \"Off\" as s0",
+                "    state \"Pair<Off, Idle>\" as s1",
+                "    state \"Named(#quot;a\\\\b#quot;)\" as s2",
+                "    s0 --> s1",
+                "    s1 --> s2 : ButtonPressed",
+                "    classDef current fill:lightblue",
+                "    class s1 current\n",
+            ]
+            .join("\n")
+        );
+        Ok(())
+    }
+
+    // The state saving its counter to the snapshot
+    #[derive(Default, Debug)]
+    struct Counted(Rc<Cell<u32>>);
+
+    impl State<RecordingIntegration> for Counted {
+        fn save(&self) -> Option<String> {
+            Some(self.0.get().to_string())
+        }
+
+        fn load(&self, data: &str) {
+            self.0.set(data.parse().unwrap_or_default());
+        }
+    }
+
+    impl Typed for Counted {
+        fn type_id(&self) -> TypeId {
+            TypeId::of::<Self>()
+        }
+    }
+
+    // should restore the machine from the snapshot
+    #[test]
+    fn should_restore_machine_from_snapshot() -> Result<(), FsmError> {
+        let build = |counter: Rc<Cell<u32>>| -> Result<(RecordingIntegration, Fsm<RecordingIntegration>), FsmError> {
+            let integration = RecordingIntegration::default();
+            let fsm = Fsm::new(integration.clone());
+            fsm.add(Off, Transitions::new().allow(Active))?;
+            fsm.add(Active, Transitions::new().allow(Off))?;
+            fsm.add_child::<Active>(Idle, Transitions::new().allow(Running))?;
+            fsm.add_child::<Active>(Running, vec![])?;
+            fsm.add(Counted(counter), vec![])?;
+            Ok((integration, fsm))
+        };
+
+        let (_, fsm) = build(Rc::new(Cell::new(3)))?;
+        fsm.goto(Active)?;
+        fsm.goto(Running)?;
+        fsm.goto(Off)?;
+
+        let snapshot = fsm.snapshot();
+        assert_eq!(snapshot.current.as_deref(), Some("Off"));
+        assert_eq!(snapshot.history["Active"], "Running");
+        assert_eq!(snapshot.data["Counted"], "3");
+
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let snapshot: FsmSnapshot = serde_json::from_str(&json).unwrap();
+
+        // the hooks are not called
+        let counter = Rc::new(Cell::new(0));
+        let (integration, restored) = build(counter.clone())?;
+        restored.restore(&snapshot, false)?;
+        assert!(integration.take().is_empty());
+        assert!(restored.current_state::<Off>()?);
+        assert_eq!(counter.get(), 3);
+        assert_eq!(restored.snapshot(), snapshot);
+
+        // the hooks are requested
+        let (integration, restored) = build(counter)?;
+        restored.restore(&snapshot, true)?;
+        assert_eq!(integration.take(), vec!["enter Off"]);
+
+        let unknown = FsmSnapshot {
+            current: Some("Unknown".into()),
+            ..FsmSnapshot::default()
+        };
+        assert_eq!(restored.restore(&unknown, false), Err(FsmError::UnknownState("Unknown".into())));
+        Ok(())
+    }
+
+    recording_states!(Slow, Fast);
+
+    // should resume the history of the compound states
+    #[test]
+    fn should_resume_history_of_compound_states() -> Result<(), FsmError> {
+        let integration = RecordingIntegration::default();
+        let fsm = Fsm::new(integration.clone());
+        fsm.set_log_capacity(3);
+
+        fsm.add(Off, Transitions::new().allow(Active).on(on(ButtonPressed).goto(Active)))?;
+        fsm.add(Active, Transitions::new().allow(Off))?;
+        fsm.add_child::<Active>(Idle, Transitions::new().allow(Running))?;
+        fsm.add_child::<Active>(Running, vec![])?;
+        fsm.add_child::<Running>(Slow, Transitions::new().allow(Fast))?;
+        fsm.add_child::<Running>(Fast, vec![])?;
+
+        fsm.goto(Off)?;
+        fsm.goto(Active)?;
+        fsm.goto(Running)?;
+        fsm.goto(Fast)?;
+        fsm.goto(Off)?;
+        integration.take();
+
+        // the shallow history resumes the substate, which is entered with its initial substate
+        fsm.set_history::<Active>(History::Shallow)?;
+        fsm.goto(Active)?;
+        assert_eq!(integration.take(), vec!["exit Off", "enter Active", "enter Running", "enter Slow"]);
+
+        fsm.goto(Fast)?;
+        fsm.goto(Off)?;
+        integration.take();
+
+        // the deep history resumes the innermost substate
+        fsm.set_history::<Active>(History::Deep)?;
+        fsm.send(ButtonPressed)?;
+        assert_eq!(integration.take(), vec!["exit Off", "enter Active", "enter Running", "enter Fast"]);
+
+        let log = fsm.transition_log();
+        assert_eq!(log.len(), 3);
+        assert_eq!((log[0].from.as_deref(), log[0].to.as_str()), (Some("Slow"), "Fast"));
+        assert_eq!((log[1].from.as_deref(), log[1].to.as_str()), (Some("Fast"), "Off"));
+
+        let last = fsm.last_transition().unwrap();
+        assert_eq!((last.from.as_deref(), last.to.as_str()), (Some("Off"), "Fast"));
+        assert_eq!(last.event.as_deref(), Some("ButtonPressed"));
+        Ok(())
+    }
+
+    type StateFacade = FacadeIntegration<StateChange>;
+
+    #[derive(Default, Debug)]
+    struct Loading;
+
+    impl State<StateFacade> for Loading {}
+
+    impl Typed for Loading {
+        fn type_id(&self) -> TypeId {
+            TypeId::of::<Self>()
+        }
+    }
+
+    #[derive(Default, Debug)]
+    struct Ready;
+
+    impl State<StateFacade> for Ready {}
+
+    impl Typed for Ready {
+        fn type_id(&self) -> TypeId {
+            TypeId::of::<Self>()
+        }
+    }
+
+    // Records the interests and the state changes
+    #[derive(Debug, Default)]
+    struct ChangeCommand {
+        log: Shared<Lock<Vec<String>>>,
+    }
+
+    impl Command<StateChange> for ChangeCommand {
+        fn execute(&self, notification: Shared<dyn Notification<StateChange>>) {
+            if let Some(change) = notification.body() {
+                let entry = format!("{} {:?} {:?}", notification.interest().0, change.exited, change.entered);
+                self.log.borrow_mut().push(entry);
+            }
+        }
+    }
+
+    // should drive the facade on the transitions
+    #[test]
+    fn should_drive_facade_on_transitions() -> Result<(), FsmError> {
+        const LOADING_ENTERED: Interest = Interest(1);
+        const LOADING_EXITED: Interest = Interest(2);
+        const CHANGED: Interest = Interest(3);
+        const FETCH: Interest = Interest(4);
+
+        let facade = BaseFacade::<StateChange>::instance("fsm-facade");
+
+        let command = Shared::new(ChangeCommand::default());
+        let log = command.log.clone();
+        for interest in [LOADING_ENTERED, LOADING_EXITED, CHANGED] {
+            facade.register_command(interest, command.clone());
+        }
+
+        let integration = FacadeIntegration::new(facade.clone())
+            .on_enter::<Loading>(LOADING_ENTERED)
+            .on_exit::<Loading>(LOADING_EXITED)
+            .on_change(CHANGED)
+            .with_command::<Loading>(FETCH, Shared::new(ChangeCommand::default()));
+
+        let fsm = Fsm::new(integration);
+        fsm.add(Loading, Transitions::new().allow(Ready))?;
+        fsm.add(Ready, vec![])?;
+
+        fsm.goto(Loading)?;
+        assert!(facade.has_command(&FETCH));
+        assert_eq!(
+            log.replace(Vec::new()),
+            vec![r#"1 [] ["Loading"]"#, r#"3 [] ["Loading"]"#]
+        );
+
+        fsm.goto(Ready)?;
+        assert!(!facade.has_command(&FETCH));
+        assert_eq!(
+            log.replace(Vec::new()),
+            vec![r#"2 ["Loading"] ["Ready"]"#, r#"3 ["Loading"] ["Ready"]"#]
+        );
+
+        BaseFacade::<StateChange>::remove_core("fsm-facade");
+        Ok(())
+    }
+
+    // should keep the commands of the application on the transitions
+    #[test]
+    fn should_keep_application_commands() -> Result<(), FsmError> {
+        const FETCH: Interest = Interest(4);
+
+        let facade = BaseFacade::<StateChange>::instance("fsm-facade-commands");
+
+        let command = Shared::new(ChangeCommand::default());
+        let log = command.log.clone();
+        facade.register_command(FETCH, command);
+
+        let scoped = Shared::new(ChangeCommand::default());
+        let scoped_log = scoped.log.clone();
+        let integration = FacadeIntegration::new(facade.clone()).with_command::<Loading>(FETCH, scoped);
+
+        let fsm = Fsm::new(integration);
+        fsm.add(Loading, Transitions::new().allow(Ready))?;
+        fsm.add(Ready, vec![])?;
+
+        fsm.goto(Loading)?;
+        facade.send(FETCH, Some(StateChange::default()));
+
+        fsm.goto(Ready)?;
+        facade.send(FETCH, Some(StateChange::default()));
+
+        assert_eq!(log.borrow().len(), 2);
+        assert!(scoped_log.borrow().is_empty());
 
-        controller.goto(MockCallbackState, None, None);
-        // MockCallbackState entered should be true
+        BaseFacade::<StateChange>::remove_core("fsm-facade-commands");
+        Ok(())
     }
 }
@@ -0,0 +1,106 @@
+use std::{any::TypeId, collections::BTreeMap, rc::Rc};
+
+use serde::{Deserialize, Serialize};
+
+use super::{Fsm, FsmError, FsmIntegration, StateDef, Typed};
+
+/// The serializable snapshot of the [Fsm].
+///
+/// The states are identified by the names of their types, without the module path,
+/// which are stable across the builds unlike the `TypeId`. The machine rejects the states
+/// with the same name, so the snapshot always refers to the single state.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FsmSnapshot {
+    /// The name of the current state
+    pub current: Option<String>,
+
+    /// The names of the substates the compound states were last in, by the compound state
+    pub history: BTreeMap<String, String>,
+
+    /// The data saved by the states, by the state
+    pub data: BTreeMap<String, String>,
+}
+
+impl<T> Fsm<T>
+where
+    T: FsmIntegration<T>,
+{
+    /// Take the snapshot of the machine
+    pub fn snapshot(&self) -> FsmSnapshot {
+        let props = self.props.borrow();
+
+        let history = props
+            .history
+            .iter()
+            .map(|(parent, child)| (props.states[parent].name.clone(), props.states[child].name.clone()))
+            .collect();
+
+        let data = props
+            .order
+            .iter()
+            .map(|type_id| &props.states[type_id])
+            .filter_map(|state_def| state_def.state.save().map(|data| (state_def.name.clone(), data)))
+            .collect();
+
+        FsmSnapshot {
+            current: props.current_state.as_ref().map(|state_def| state_def.name.clone()),
+            history,
+            data,
+        }
+    }
+
+    /// Put the machine back in the state of the snapshot.
+    ///
+    /// The current state is replaced without exiting it. If `enter` is set, the restored state
+    /// is entered from the outermost state down, otherwise no hooks are called.
+    /// The states of the snapshot should be added to the machine.
+    pub fn restore(&self, snapshot: &FsmSnapshot, enter: bool) -> Result<(), FsmError> {
+        let (data, entered) = {
+            let mut props = self.props.borrow_mut();
+
+            let find = |name: &String| -> Result<Rc<StateDef<T>>, FsmError> {
+                props
+                    .states
+                    .values()
+                    .find(|state_def| state_def.name == *name)
+                    .cloned()
+                    .ok_or_else(|| FsmError::UnknownState(name.clone()))
+            };
+
+            let current = snapshot.current.as_ref().map(find).transpose()?;
+
+            let history = snapshot
+                .history
+                .iter()
+                .map(|(parent, child)| Ok((find(parent)?.type_id(), find(child)?.type_id())))
+                .collect::<Result<Vec<(TypeId, TypeId)>, FsmError>>()?;
+
+            let data = snapshot
+                .data
+                .iter()
+                .map(|(name, data)| Ok((find(name)?, data)))
+                .collect::<Result<Vec<_>, FsmError>>()?;
+
+            props.history = history.into_iter().collect();
+            props.current_state = current.clone();
+
+            let entered = match current {
+                Some(current) if enter => props.path(&current),
+                _ => Vec::new(),
+            };
+
+            (data, entered)
+        };
+
+        // the props are released so the states are able to access the fsm
+        for (state_def, data) in data {
+            state_def.state.load(data);
+        }
+
+        if !entered.is_empty() {
+            self.integration.transition(&[], &entered, &|| {});
+        }
+
+        Ok(())
+    }
+}
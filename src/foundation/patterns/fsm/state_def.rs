@@ -10,8 +10,12 @@ where
 {
     /// Represens the state
     pub state: Box<dyn State<T>>,
+    /// Represens the name of the state type, without the module path
+    pub name: String,
     /// Represens the state transitions
     pub transitions: Transitions<T>,
+    /// Represens the parent of the state
+    pub parent: Option<TypeId>,
 }
 
 impl<T> StateDef<T>
@@ -19,10 +23,28 @@ where
     T: FsmIntegration<T>,
 {
     /// Create new state definition
-    pub fn new(state: impl State<T> + 'static, transitions: Transitions<T>) -> Self {
+    pub fn new<S>(state: S, transitions: Transitions<T>) -> Self
+    where
+        S: State<T> + 'static,
+    {
         Self {
             state: Box::new(state),
+            name: type_name::<S>(),
             transitions,
+            parent: None,
+        }
+    }
+
+    /// Create new definition of the child state
+    pub fn with_parent<S>(state: S, transitions: Transitions<T>, parent: TypeId) -> Self
+    where
+        S: State<T> + 'static,
+    {
+        Self {
+            state: Box::new(state),
+            name: type_name::<S>(),
+            transitions,
+            parent: Some(parent),
         }
     }
 }
@@ -35,3 +57,23 @@ where
         self.state.type_id()
     }
 }
+
+// The name of the type without the module paths, like the Debug of the unit struct,
+// the paths of the generic arguments are stripped as well: `a::Bar<b::Baz>` is `Bar<Baz>`
+pub(super) fn type_name<S: ?Sized>() -> String {
+    let mut name = String::new();
+    let mut path = String::new();
+
+    for c in std::any::type_name::<S>().chars() {
+        if c.is_alphanumeric() || c == '_' || c == ':' {
+            path.push(c);
+        } else {
+            name.push_str(path.rsplit("::").next().unwrap_or_default());
+            path.clear();
+            name.push(c);
+        }
+    }
+    name.push_str(path.rsplit("::").next().unwrap_or_default());
+
+    name
+}
@@ -0,0 +1,242 @@
+use std::{
+    any::{Any, TypeId},
+    fmt,
+    marker::PhantomData,
+};
+
+use super::{state_def::type_name, FsmIntegration, State, Typed};
+
+// Type erased guard and action of the transition
+type Guard = Box<dyn Fn(&dyn Any) -> bool>;
+type Action = Box<dyn Fn(&dyn Any)>;
+
+/// Start the transition on the event.
+///
+/// ```ignore
+/// fsm.add(Off, on(ButtonPressed).goto(On));
+/// fsm.send(ButtonPressed)?;
+/// ```
+pub fn on<E>(_event: E) -> On<E>
+where
+    E: fmt::Debug + 'static,
+{
+    On {
+        name: type_name::<E>(),
+        guard: None,
+        action: None,
+        event: PhantomData,
+    }
+}
+
+/// The builder of the transition on the event `E`, created with [on].
+pub struct On<E> {
+    name: String,
+    guard: Option<Guard>,
+    action: Option<Action>,
+    event: PhantomData<E>,
+}
+
+impl<E> On<E>
+where
+    E: fmt::Debug + 'static,
+{
+    /// Take the transition only if the guard allows it
+    pub fn when(mut self, guard: impl Fn(&E) -> bool + 'static) -> Self {
+        self.guard = Some(Box::new(move |event| {
+            event.downcast_ref::<E>().map(&guard).unwrap_or_default()
+        }));
+        self
+    }
+
+    /// Call the action when the transition is taken, before the states are exited
+    pub fn action(mut self, action: impl Fn(&E) + 'static) -> Self {
+        self.action = Some(Box::new(move |event| {
+            if let Some(event) = event.downcast_ref::<E>() {
+                action(event)
+            }
+        }));
+        self
+    }
+
+    /// Finish the transition to the target state
+    pub fn goto<T, S>(self, target: S) -> EventTransition<T>
+    where
+        T: FsmIntegration<T>,
+        S: State<T> + 'static,
+    {
+        EventTransition {
+            event: TypeId::of::<E>(),
+            name: self.name,
+            target: Box::new(target),
+            guard: self.guard,
+            action: self.action,
+        }
+    }
+}
+
+/// The transition to the target state on the event.
+///
+/// Created with [on].
+pub struct EventTransition<T>
+where
+    T: FsmIntegration<T>,
+{
+    event: TypeId,
+    name: String,
+    target: Box<dyn State<T>>,
+    guard: Option<Guard>,
+    action: Option<Action>,
+}
+
+impl<T> EventTransition<T>
+where
+    T: FsmIntegration<T>,
+{
+    /// Add one more transition of the same state
+    pub fn and(self, transition: EventTransition<T>) -> Transitions<T> {
+        Transitions::from(self).on(transition)
+    }
+
+    /// Retrieve the TypeId of the event
+    pub fn event(&self) -> TypeId {
+        self.event
+    }
+
+    /// Retrieve the name of the event
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Retrieve the target state
+    pub fn target(&self) -> &dyn State<T> {
+        self.target.as_ref()
+    }
+
+    /// Check if the transition has the guard
+    pub fn has_guard(&self) -> bool {
+        self.guard.is_some()
+    }
+
+    /// Check if the transition has the action
+    pub fn has_action(&self) -> bool {
+        self.action.is_some()
+    }
+
+    /// Check if the guard allows the transition on the event
+    pub fn allows(&self, event: &dyn Any) -> bool {
+        self.guard.as_ref().map(|guard| guard(event)).unwrap_or(true)
+    }
+
+    /// Call the action of the transition
+    pub fn act(&self, event: &dyn Any) {
+        if let Some(action) = self.action.as_ref() {
+            action(event);
+        }
+    }
+}
+
+impl<T> fmt::Debug for EventTransition<T>
+where
+    T: FsmIntegration<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EventTransition")
+            .field("event", &self.name)
+            .field("target", &self.target)
+            .finish()
+    }
+}
+
+/// The transitions of the state.
+///
+/// The state is left either by [goto](super::Fsm::goto) to the allowed states,
+/// or by [send](super::Fsm::send) of the events.
+pub struct Transitions<T>
+where
+    T: FsmIntegration<T>,
+{
+    states: Vec<Box<dyn State<T>>>,
+    events: Vec<EventTransition<T>>,
+}
+
+impl<T> Transitions<T>
+where
+    T: FsmIntegration<T>,
+{
+    /// Create the empty transitions
+    pub fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            events: Vec::new(),
+        }
+    }
+
+    /// Allow the transition to the state with [goto](super::Fsm::goto)
+    pub fn allow(mut self, state: impl State<T> + 'static) -> Self {
+        self.states.push(Box::new(state));
+        self
+    }
+
+    /// Add the transition on the event
+    pub fn on(mut self, transition: EventTransition<T>) -> Self {
+        self.events.push(transition);
+        self
+    }
+
+    /// Retrieve the states allowed to [goto](super::Fsm::goto)
+    pub fn states(&self) -> &[Box<dyn State<T>>] {
+        &self.states
+    }
+
+    /// Retrieve the transitions on the events
+    pub fn events(&self) -> &[EventTransition<T>] {
+        &self.events
+    }
+
+    /// Check if the transition to the state is allowed
+    pub fn allows(&self, type_id: TypeId) -> bool {
+        self.states.iter().any(|state| Typed::type_id(state.as_ref()) == type_id)
+    }
+}
+
+impl<T> Default for Transitions<T>
+where
+    T: FsmIntegration<T>,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> From<Vec<Box<dyn State<T>>>> for Transitions<T>
+where
+    T: FsmIntegration<T>,
+{
+    fn from(states: Vec<Box<dyn State<T>>>) -> Self {
+        Self {
+            states,
+            events: Vec::new(),
+        }
+    }
+}
+
+impl<T> From<EventTransition<T>> for Transitions<T>
+where
+    T: FsmIntegration<T>,
+{
+    fn from(transition: EventTransition<T>) -> Self {
+        Self::new().on(transition)
+    }
+}
+
+impl<T> fmt::Debug for Transitions<T>
+where
+    T: FsmIntegration<T>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Transitions")
+            .field("states", &self.states)
+            .field("events", &self.events)
+            .finish()
+    }
+}
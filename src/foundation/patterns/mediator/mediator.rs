@@ -1,27 +1,32 @@
-use std::{fmt, rc::Rc};
+use std::fmt;
 
 use crate::{
-    foundation::patterns::facade::BaseFacade,
-    prelude::{Facade, Interest, Mediator, Notification, Notifier, NotifyContext, Singleton, View},
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{CoreKey, Interest, MaybeSendSync, Mediator, Notification, Notifier, NotifyContext, Shared, View},
 };
 
 /// A base [Mediator] implementation.
-
 pub struct BaseMediator<Body> {
     // The view component
-    view_component: Option<Rc<dyn View<Body>>>,
+    view_component: Option<Shared<dyn View<Body>>>,
+
+    // Sends notifications through the Core the mediator is registered with
+    notifier: BaseNotifier,
 }
 
 impl<Body> BaseMediator<Body> {
-    /// The name of the [Mediator].
-    ///
-    /// Typically, a [Mediator] will be written to serve
-    /// one specific control or group controls and so,
-    /// will not have a need to be dynamically named.
+    // The name of the [Mediator].
+    //
+    // Typically, a [Mediator] will be written to serve
+    // one specific control or group controls and so,
+    // will not have a need to be dynamically named.
 
     /// Constructor.
-    pub fn new(view_component: Option<Rc<dyn View<Body>>>) -> Self {
-        Self { view_component }
+    pub fn new(view_component: Option<Shared<dyn View<Body>>>) -> Self {
+        Self {
+            view_component,
+            notifier: BaseNotifier::new(),
+        }
     }
 }
 
@@ -29,21 +34,25 @@ impl<Body> Mediator<Body> for BaseMediator<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    fn view_component(&self) -> Option<Rc<dyn View<Body>>> {
+    fn view_component(&self) -> Option<Shared<dyn View<Body>>> {
         self.view_component.as_ref().map(|c| c.clone())
     }
 
-    fn handle_notification(&self, _notification: Rc<dyn Notification<Body>>) {}
+    fn handle_notification(&self, _notification: Shared<dyn Notification<Body>>) {}
 
     fn list_notification_interests(&self) -> &[Interest] {
         &[]
     }
 
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
     fn on_register(&self) {}
 
     fn on_remove(&self) {}
 
-    fn set_view_component(&mut self, view_component: Option<Rc<dyn View<Body>>>) {
+    fn set_view_component(&mut self, view_component: Option<Shared<dyn View<Body>>>) {
         self.view_component = view_component;
     }
 }
@@ -59,11 +68,10 @@ where
 
 impl<Body> Notifier<Body> for BaseMediator<Body>
 where
-    Body: fmt::Debug + 'static,
+    Body: fmt::Debug + MaybeSendSync + 'static,
 {
     fn send(&self, interest: Interest, body: Option<Body>) {
-        log::error!("You should implement yourself Mediator");
-        BaseFacade::<Body>::global().send(interest, body);
+        self.notifier.send(interest, body);
     }
 }
 
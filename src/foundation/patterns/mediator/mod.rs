@@ -45,5 +45,6 @@
 //! frequently accessed Proxies in its constructor. This reduces
 //! repetitive retrieveProxy calls to obtain the same reference.
 
+#[allow(clippy::module_inception)]
 mod mediator;
 pub use self::mediator::*;
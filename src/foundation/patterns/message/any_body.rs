@@ -0,0 +1,54 @@
+use std::{any::Any, fmt};
+
+use crate::prelude::{MaybeSendSync, Message, TypedBody};
+
+// Type erased value, which is still able to be formatted
+trait AnyValue: fmt::Debug + MaybeSendSync {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T> AnyValue for T
+where
+    T: fmt::Debug + MaybeSendSync + 'static,
+{
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// A [TypedBody] which carries a body of any type.
+pub struct AnyBody {
+    value: Box<dyn AnyValue>,
+}
+
+impl AnyBody {
+    /// Create new AnyBody with the value
+    pub fn new<T>(value: T) -> Self
+    where
+        T: fmt::Debug + MaybeSendSync + 'static,
+    {
+        Self { value: Box::new(value) }
+    }
+
+    /// Retrieve the value, if it has the given type
+    pub fn downcast_ref<T: 'static>(&self) -> Option<&T> {
+        // deref the box, as the box is an AnyValue itself
+        (*self.value).as_any().downcast_ref::<T>()
+    }
+}
+
+impl TypedBody for AnyBody {
+    fn from_message<M: Message>(body: M::Body) -> Self {
+        Self::new(body)
+    }
+
+    fn message<M: Message>(&self) -> Option<&M::Body> {
+        self.downcast_ref::<M::Body>()
+    }
+}
+
+impl fmt::Debug for AnyBody {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AnyBody").field(&self.value).finish()
+    }
+}
@@ -0,0 +1,17 @@
+//! Typed Messages
+//!
+//! The Core actors are generic over one Body type, which usually ends up
+//! as a giant enum matched by hand. The typed Messages tie the type of the
+//! body to its Interest instead, so the body is sent with send_typed and
+//! received by the TypedCommands and Mediators as its own type.
+//!
+//! The Body of the Core carries the bodies of all of the Messages, it is
+//! either the built-in AnyBody, or your own type implementing TypedBody.
+//! As the Messages are sent with their Interests, the typed Messages and
+//! the Interest based API are used side by side.
+
+mod any_body;
+pub use self::any_body::*;
+
+mod typed_command;
+pub use self::typed_command::*;
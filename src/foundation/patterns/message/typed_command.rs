@@ -0,0 +1,153 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::prelude::{Command, CoreKey, Message, Notification, Shared, TypedBody, TypedCommand, TypedNotification};
+
+/// Adapts the [TypedCommand] to the [Command] of the Core.
+///
+/// The body of the [Notification] is passed to the [TypedCommand]
+/// as the body of its [Message].
+pub struct TypedCommandAdapter<M, Body>
+where
+    M: Message,
+    Body: TypedBody,
+{
+    command: Shared<dyn TypedCommand<M>>,
+    _body: PhantomData<fn() -> Body>,
+}
+
+impl<M, Body> TypedCommandAdapter<M, Body>
+where
+    M: Message,
+    Body: TypedBody,
+{
+    /// Create new TypedCommandAdapter for the [TypedCommand]
+    pub fn new(command: Shared<dyn TypedCommand<M>>) -> Self {
+        Self {
+            command,
+            _body: PhantomData,
+        }
+    }
+}
+
+impl<M, Body> Command<Body> for TypedCommandAdapter<M, Body>
+where
+    M: Message,
+    Body: TypedBody,
+{
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.command.initialize_notifier(key);
+    }
+
+    fn execute(&self, notification: Shared<dyn Notification<Body>>) {
+        match notification.message::<M>() {
+            Some(message) => self.command.execute(message),
+            None => log::error!("Unexpected body [TypedCommandAdapter] {:?}", notification),
+        }
+    }
+}
+
+impl<M, Body> fmt::Debug for TypedCommandAdapter<M, Body>
+where
+    M: Message,
+    Body: TypedBody,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedCommandAdapter").field("command", &self.command).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::{
+        foundation::patterns::{facade::BaseFacade, message::AnyBody},
+        prelude::*,
+    };
+
+    #[derive(Debug)]
+    struct UserLoggedIn {
+        name: String,
+    }
+
+    impl Message for UserLoggedIn {
+        type Body = Self;
+
+        const INTEREST: Interest = Interest::new(1, 1);
+    }
+
+    struct Visited;
+
+    impl Message for Visited {
+        type Body = u32;
+
+        const INTEREST: Interest = Interest::new(1, 2);
+    }
+
+    #[derive(Debug, Default)]
+    struct LoginCommand {
+        logins: Shared<Lock<Vec<String>>>,
+    }
+
+    impl TypedCommand<UserLoggedIn> for LoginCommand {
+        fn execute(&self, message: &UserLoggedIn) {
+            self.logins.borrow_mut().push(message.name.clone());
+        }
+    }
+
+    #[derive(Debug, Default)]
+    struct VisitMediator {
+        visits: Shared<AtomicU32>,
+    }
+
+    impl NotifyContext for VisitMediator {
+        fn id(&self) -> u64 {
+            0x01
+        }
+    }
+
+    impl Mediator<AnyBody> for VisitMediator {
+        fn view_component(&self) -> Option<Shared<dyn View<AnyBody>>> {
+            None
+        }
+
+        fn set_view_component(&mut self, _component: Option<Shared<dyn View<AnyBody>>>) {}
+
+        fn list_notification_interests(&self) -> &[Interest] {
+            &[Visited::INTEREST, UserLoggedIn::INTEREST]
+        }
+
+        fn handle_notification(&self, notification: Shared<dyn Notification<AnyBody>>) {
+            if let Some(visits) = notification.message::<Visited>() {
+                self.visits.fetch_add(*visits, Ordering::SeqCst);
+            }
+        }
+
+        fn on_register(&self) {}
+
+        fn on_remove(&self) {}
+    }
+
+    #[test]
+    fn should_deliver_typed_messages() {
+        let facade = BaseFacade::<AnyBody>::instance("typed-messages");
+
+        let command = Shared::new(LoginCommand::default());
+        let logins = command.logins.clone();
+        facade.register_typed_command::<UserLoggedIn>(command);
+        assert!(facade.has_command(&UserLoggedIn::INTEREST));
+
+        let mediator = Shared::new(VisitMediator::default());
+        let visits = mediator.visits.clone();
+        facade.register_mediator(mediator);
+
+        facade.send_typed::<UserLoggedIn>(UserLoggedIn { name: "alice".into() });
+        facade.send_typed::<Visited>(2);
+        facade.send(Visited::INTEREST, Some(AnyBody::new(3u32)));
+
+        assert_eq!(*logins.borrow(), vec!["alice".to_string()]);
+        assert_eq!(visits.load(Ordering::SeqCst), 5);
+
+        BaseFacade::<AnyBody>::remove_core("typed-messages");
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+use crate::prelude::{Middleware, Next, Notification, Shared};
+
+/// A [Middleware] which logs every [Notification] through the `log` crate.
+#[derive(Debug, Clone, Copy)]
+pub struct LoggingMiddleware {
+    level: log::Level,
+}
+
+impl LoggingMiddleware {
+    /// Create new LoggingMiddleware with the given log level
+    pub fn new(level: log::Level) -> Self {
+        Self { level }
+    }
+}
+
+impl Default for LoggingMiddleware {
+    fn default() -> Self {
+        Self::new(log::Level::Debug)
+    }
+}
+
+impl<Body> Middleware<Body> for LoggingMiddleware
+where
+    Body: fmt::Debug + 'static,
+{
+    fn handle(&self, notification: Shared<dyn Notification<Body>>, next: Next<Body>) {
+        log::log!(self.level, "Send Notification [LoggingMiddleware] {:?}", notification);
+        next.run(notification);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    use super::LoggingMiddleware;
+
+    #[derive(Debug, Default)]
+    struct CountCommand {
+        executed: Shared<AtomicU32>,
+    }
+
+    impl Command<u32> for CountCommand {
+        fn execute(&self, _notification: Shared<dyn Notification<u32>>) {
+            self.executed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn should_pass_every_notification() {
+        let facade = BaseFacade::<u32>::instance("logging");
+
+        let command = Shared::new(CountCommand::default());
+        let executed = command.executed.clone();
+
+        facade.register_command(Interest(1), command);
+        facade.register_middleware(Shared::new(LoggingMiddleware::new(log::Level::Info)));
+        facade.register_middleware(Shared::new(LoggingMiddleware::default()));
+
+        for _ in 0..3 {
+            facade.send(Interest(1), None);
+        }
+
+        assert_eq!(executed.load(Ordering::SeqCst), 3);
+
+        BaseFacade::<u32>::remove_core("logging");
+    }
+}
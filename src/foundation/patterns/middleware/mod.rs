@@ -0,0 +1,18 @@
+//! Middlewares
+//!
+//! The Middlewares intercept the Notifications sent through the Facade,
+//! before they are broadcast by the View. Each Middleware receives the
+//! Notification and the rest of the chain, so it is able to log,
+//! transform, delay, drop or fan out the Notifications.
+//!
+//! The Middlewares are registered with the Facade, and called in the
+//! order they are registered.
+
+mod logging;
+pub use self::logging::*;
+
+mod sampling;
+pub use self::sampling::*;
+
+mod throttle;
+pub use self::throttle::*;
@@ -0,0 +1,110 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+};
+
+use crate::prelude::{Interest, Lock, Middleware, Next, Notification, Shared};
+
+/// A [Middleware] which samples the [Notification]'s by [Interest].
+///
+/// Only the first of each `rate` [Notification]'s of the same [Interest] passes.
+#[derive(Debug)]
+pub struct SamplingMiddleware {
+    rate: u64,
+
+    // Sampled interests, all of them if not set
+    interests: Option<HashSet<Interest>>,
+
+    // The number of the notifications of each interest since the last passed one
+    counters: Lock<HashMap<Interest, u64>>,
+}
+
+impl SamplingMiddleware {
+    /// Create new SamplingMiddleware which passes one of each `rate` [Notification]'s
+    pub fn new(rate: u64) -> Self {
+        Self {
+            rate: rate.max(1),
+            interests: None,
+            counters: Lock::new(HashMap::new()),
+        }
+    }
+
+    /// Sample the [Notification]'s of the given [Interest]'s only
+    pub fn with_interests(mut self, interests: &[Interest]) -> Self {
+        self.interests = Some(interests.iter().copied().collect());
+        self
+    }
+
+    fn pass(&self, interest: Interest) -> bool {
+        if let Some(interests) = self.interests.as_ref() {
+            if !interests.contains(&interest) {
+                return true;
+            }
+        }
+
+        let mut counters = self.counters.borrow_mut();
+        let counter = counters.entry(interest).or_default();
+        let pass = *counter == 0;
+        *counter = (*counter + 1) % self.rate;
+        pass
+    }
+}
+
+impl<Body> Middleware<Body> for SamplingMiddleware
+where
+    Body: fmt::Debug + 'static,
+{
+    fn handle(&self, notification: Shared<dyn Notification<Body>>, next: Next<Body>) {
+        if self.pass(notification.interest()) {
+            next.run(notification);
+        } else {
+            log::trace!("Drop Notification [SamplingMiddleware] {:?}", notification);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    use super::SamplingMiddleware;
+
+    #[derive(Debug, Default)]
+    struct RecordCommand {
+        bodies: Shared<Lock<Vec<u32>>>,
+        executed: Shared<AtomicU32>,
+    }
+
+    impl Command<u32> for RecordCommand {
+        fn execute(&self, notification: Shared<dyn Notification<u32>>) {
+            self.bodies.borrow_mut().extend(notification.body().copied());
+            self.executed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn should_pass_every_nth_notification_by_interest() {
+        let facade = BaseFacade::<u32>::instance("sampling");
+
+        let sampled = Shared::new(RecordCommand::default());
+        let passed = Shared::new(RecordCommand::default());
+        let sampled_bodies = sampled.bodies.clone();
+        let passed_count = passed.executed.clone();
+
+        facade.register_command(Interest(1), sampled);
+        facade.register_command(Interest(2), passed);
+        facade.register_middleware(Shared::new(SamplingMiddleware::new(3).with_interests(&[Interest(1)])));
+
+        for body in 0..7 {
+            facade.send(Interest(1), Some(body));
+            facade.send(Interest(2), Some(body));
+        }
+
+        assert_eq!(*sampled_bodies.borrow(), vec![0, 3, 6]);
+        assert_eq!(passed_count.load(Ordering::SeqCst), 7);
+
+        BaseFacade::<u32>::remove_core("sampling");
+    }
+}
@@ -0,0 +1,119 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt,
+    time::{Duration, Instant},
+};
+
+use crate::prelude::{Interest, Lock, Middleware, Next, Notification, Shared};
+
+/// A [Middleware] which throttles the [Notification]'s by [Interest].
+///
+/// A [Notification] is dropped if the previous one of the same [Interest]
+/// has passed less than the interval ago.
+#[derive(Debug)]
+pub struct ThrottleMiddleware {
+    interval: Duration,
+
+    // Throttled interests, all of them if not set
+    interests: Option<HashSet<Interest>>,
+
+    // The time the last notification of each interest has passed
+    passed: Lock<HashMap<Interest, Instant>>,
+}
+
+impl ThrottleMiddleware {
+    /// Create new ThrottleMiddleware which throttles the [Notification]'s of all of the [Interest]'s
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            interests: None,
+            passed: Lock::new(HashMap::new()),
+        }
+    }
+
+    /// Throttle the [Notification]'s of the given [Interest]'s only
+    pub fn with_interests(mut self, interests: &[Interest]) -> Self {
+        self.interests = Some(interests.iter().copied().collect());
+        self
+    }
+
+    fn pass(&self, interest: Interest) -> bool {
+        if let Some(interests) = self.interests.as_ref() {
+            if !interests.contains(&interest) {
+                return true;
+            }
+        }
+
+        let now = Instant::now();
+        let mut passed = self.passed.borrow_mut();
+        match passed.get(&interest) {
+            Some(last) if now.duration_since(*last) < self.interval => false,
+            _ => {
+                passed.insert(interest, now);
+                true
+            }
+        }
+    }
+}
+
+impl<Body> Middleware<Body> for ThrottleMiddleware
+where
+    Body: fmt::Debug + 'static,
+{
+    fn handle(&self, notification: Shared<dyn Notification<Body>>, next: Next<Body>) {
+        if self.pass(notification.interest()) {
+            next.run(notification);
+        } else {
+            log::debug!("Drop Notification [ThrottleMiddleware] {:?}", notification);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::atomic::{AtomicU32, Ordering},
+        time::Duration,
+    };
+
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    use super::ThrottleMiddleware;
+
+    #[derive(Debug, Default)]
+    struct CountCommand {
+        executed: Shared<AtomicU32>,
+    }
+
+    impl Command<u32> for CountCommand {
+        fn execute(&self, _notification: Shared<dyn Notification<u32>>) {
+            self.executed.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn should_throttle_notifications_by_interest() {
+        let facade = BaseFacade::<u32>::instance("throttle");
+
+        let throttled = Shared::new(CountCommand::default());
+        let passed = Shared::new(CountCommand::default());
+        let throttled_count = throttled.executed.clone();
+        let passed_count = passed.executed.clone();
+
+        facade.register_command(Interest(1), throttled);
+        facade.register_command(Interest(2), passed);
+        facade.register_middleware(Shared::new(
+            ThrottleMiddleware::new(Duration::from_secs(3600)).with_interests(&[Interest(1)]),
+        ));
+
+        for _ in 0..3 {
+            facade.send(Interest(1), None);
+            facade.send(Interest(2), None);
+        }
+
+        assert_eq!(throttled_count.load(Ordering::SeqCst), 1);
+        assert_eq!(passed_count.load(Ordering::SeqCst), 3);
+
+        BaseFacade::<u32>::remove_core("throttle");
+    }
+}
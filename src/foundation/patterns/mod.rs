@@ -10,8 +10,14 @@ pub mod fsm;
 
 pub mod mediator;
 
+pub mod message;
+
+pub mod middleware;
+
 pub mod observer;
 
 pub mod proxy;
 
+pub mod store;
+
 pub mod builder;
\ No newline at end of file
@@ -26,5 +26,6 @@ pub use self::notification::*;
 mod notifier;
 pub use self::notifier::*;
 
+#[allow(clippy::module_inception)]
 mod observer;
 pub use self::observer::*;
@@ -1,6 +1,6 @@
 use std::fmt;
 
-use crate::prelude::{Interest, Notification};
+use crate::prelude::{Interest, MaybeSendSync, Notification};
 
 /// A base [Notification] implementation.
 ///
@@ -29,7 +29,6 @@ use crate::prelude::{Interest, Notification};
 /// [Command]: crate::prelude::Command
 /// [Proxy]: crate::prelude::Proxy
 /// [Mediator]: crate::prelude::Mediator
-
 pub struct BaseNotification<Body>
 where
     Body: fmt::Debug + 'static,
@@ -53,7 +52,7 @@ where
 
 impl<Body> Notification<Body> for BaseNotification<Body>
 where
-    Body: fmt::Debug + 'static,
+    Body: fmt::Debug + MaybeSendSync + 'static,
 {
     fn body(&self) -> Option<&Body> {
         self.body.as_ref()
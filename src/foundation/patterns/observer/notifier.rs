@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use crate::{
     foundation::patterns::facade::BaseFacade,
-    prelude::{Facade, Interest, Notifier, Singleton},
+    prelude::{CoreKey, Facade, Interest, Lock, MaybeSendSync, Multiton, Notifier, Shared},
 };
 
 /// A Base [Notifier] implementation.
@@ -16,33 +16,72 @@ use crate::{
 ///
 /// The [Notifier] class, which all of the above mentioned classes
 /// extend, provides an initialized reference to the [Facade]
-/// Singleton, which is required for the convienience method
+/// Multiton, which is required for the convienience method
 /// for sending [Notification]'s, but also eases implementation as these
 /// classes have frequent [Facade] interactions and usually require
 /// access to the facade anyway.
-/// 
+///
+/// The [Notifier] receives the multiton key of its Core with
+/// [initialize_notifier](BaseNotifier::initialize_notifier), which is called by
+/// the Core actors when the owner is registered.
+///
 /// [MacroCommand]: crate::foundation::patterns::command::MacroCommand
 /// [Notification]: crate::prelude::Notification
 /// [Command]: crate::prelude::Command
 /// [Mediator]: crate::prelude::Mediator
 /// [Proxy]: crate::prelude::Proxy
 /// [Facade]: crate::prelude::Facade
-
-pub struct BaseNotifier;
+#[derive(Default)]
+pub struct BaseNotifier {
+    // The Multiton Key for this app
+    key: Lock<Option<CoreKey>>,
+}
 
 impl BaseNotifier {
     /// Create new BaseNotifier
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Initialize this [Notifier] instance.
+    ///
+    /// This is how a [Notifier] gets its multiton key.
+    /// Calls to [send](Notifier::send) or to access the
+    /// facade will fail until after this method has been called.
+    pub fn initialize_notifier(&self, key: &CoreKey) {
+        self.key.replace(Some(key.clone()));
+    }
+
+    /// Retrieve the multiton key, if the [Notifier] is initialized
+    pub fn key(&self) -> Option<CoreKey> {
+        self.key.borrow().clone()
+    }
+
+    /// Retrieve the [Facade] of the Core, if the [Notifier] is initialized
+    ///
+    /// [Facade]: crate::prelude::Facade
+    pub fn facade<Body>(&self) -> Option<Shared<BaseFacade<Body>>>
+    where
+        Body: Debug + 'static,
+    {
+        self.key.borrow().as_ref().map(BaseFacade::<Body>::instance)
     }
 }
 
 impl<Body> Notifier<Body> for BaseNotifier
 where
-    Body: Debug + 'static,
+    Body: Debug + MaybeSendSync + 'static,
 {
     fn send(&self, interest: Interest, body: Option<Body>) {
-        log::error!("You should implement yourself Notifier");
-        BaseFacade::<Body>::global().send(interest, body);
+        match self.facade::<Body>() {
+            Some(facade) => facade.send(interest, body),
+            None => log::error!("Notifier is not initialized, the {:?} is not sent", interest),
+        }
+    }
+}
+
+impl Debug for BaseNotifier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BaseNotifier").field("key", &self.key.borrow()).finish()
     }
 }
@@ -1,6 +1,6 @@
-use std::{fmt, rc::Rc};
+use std::fmt;
 
-use crate::prelude::{Notification, NotifyContext, Observer};
+use crate::prelude::{Notification, NotifyContext, NotifyMethod, Observer, Propagation, Shared};
 
 /// A base [Observer] implementation.
 ///
@@ -15,13 +15,12 @@ use crate::prelude::{Notification, NotifyContext, Observer};
 /// - Provide methods for setting the notification method and context.
 /// - Provide a method for notifying the interested object.
 ///
-
 pub struct BaseObserver<Body>
 where
     Body: fmt::Debug + 'static,
 {
-    notify: Box<dyn Fn(Rc<dyn Notification<Body>>)>,
-    context: Rc<dyn NotifyContext>,
+    notify: NotifyMethod<Body>,
+    context: Shared<dyn NotifyContext>,
 }
 
 impl<Body> BaseObserver<Body>
@@ -31,13 +30,13 @@ where
     /// Constructor.
     ///
     /// The notification method on the interested object should take
-    /// one parameter of type [Notification]
-    pub fn new(notify: Box<dyn Fn(Rc<dyn Notification<Body>>)>, context: Rc<dyn NotifyContext>) -> Self {
+    /// one parameter of type [Notification], and return the [Propagation]
+    pub fn new(notify: NotifyMethod<Body>, context: Shared<dyn NotifyContext>) -> Self {
         Self { notify, context }
     }
 
     // Get the notification method.
-    fn method(&self) -> &impl Fn(Rc<dyn Notification<Body>>) {
+    fn method(&self) -> &impl Fn(Shared<dyn Notification<Body>>) -> Propagation {
         &self.notify
     }
 }
@@ -47,23 +46,23 @@ where
     Body: fmt::Debug + 'static,
 {
     // Get the notification context.
-    fn context(&self) -> &Rc<dyn NotifyContext> {
+    fn context(&self) -> &Shared<dyn NotifyContext> {
         &self.context
     }
 
-    fn compare_context(&self, object: &Rc<dyn NotifyContext>) -> bool {
+    fn compare_context(&self, object: &Shared<dyn NotifyContext>) -> bool {
         object.id() == self.context.id()
     }
 
-    fn notify(&self, notification: Rc<dyn Notification<Body>>) {
-        self.method()(notification);
+    fn notify(&self, notification: Shared<dyn Notification<Body>>) -> Propagation {
+        self.method()(notification)
     }
 
-    fn set_context(&mut self, context: Rc<dyn NotifyContext>) {
+    fn set_context(&mut self, context: Shared<dyn NotifyContext>) {
         self.context = context;
     }
 
-    fn set_method(&mut self, notify: Box<dyn Fn(Rc<dyn Notification<Body>>)>) {
+    fn set_method(&mut self, notify: NotifyMethod<Body>) {
         self.notify = notify;
     }
 }
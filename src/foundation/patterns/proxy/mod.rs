@@ -55,6 +55,29 @@
 //! - Smart Proxy, loads data object into memory on first
 //!   access, performs reference counting, allows locking
 //!   of object to ensure no other object can change it.
+//!
+//! ## Observable Proxy
+//!
+//! The [ObservableProxy] tells about the changes of its Data, to the subscribed
+//! listeners and through the changed Notification, so the Mediators are able
+//! to react to the Model directly.
+//!
+//! ## Persistent Proxy
+//!
+//! The [PersistentProxy] keeps its Data in the [Storage](crate::prelude::Storage),
+//! either the [MemoryStorage], the [JsonFileStorage] or the one of the application.
+//! The Data is loaded when the proxy is registered and saved on change or on flush,
+//! along with the version of its schema, so the older documents are migrated on load.
 
+#[allow(clippy::module_inception)]
 mod proxy;
 pub use self::proxy::*;
+
+mod observable_proxy;
+pub use self::observable_proxy::*;
+
+mod persistent_proxy;
+pub use self::persistent_proxy::*;
+
+mod storage;
+pub use self::storage::*;
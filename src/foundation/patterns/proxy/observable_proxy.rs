@@ -0,0 +1,298 @@
+use std::{fmt, marker::PhantomData};
+
+use crate::{
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{CoreKey, Interest, Lock, MaybeSendSync, Notifier, Proxy, Shared},
+};
+
+/// Listens to the changes of the [ObservableProxy], called with the old and the new Data
+#[cfg(not(feature = "sync"))]
+pub type ChangeListener<Data> = Shared<dyn Fn(&Data, &Data)>;
+
+/// Listens to the changes of the [ObservableProxy], called with the old and the new Data
+#[cfg(feature = "sync")]
+pub type ChangeListener<Data> = Shared<dyn Fn(&Data, &Data) + Send + Sync>;
+
+/// Identifies the subscription to the [ObservableProxy]
+pub type SubscriptionId = u64;
+
+/// Represents the change of the [ObservableProxy] Data, the body of its changed notification
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Change<Data> {
+    /// The Data before the change
+    pub old: Data,
+    /// The Data after the change
+    pub new: Data,
+}
+
+// The mutations collected by the batch
+struct Batch<Data> {
+    depth: usize,
+    old: Option<Data>,
+}
+
+/// A [Proxy] which tells about the changes of its Data.
+///
+/// Each change is passed to the subscribed listeners and, if the changed Interest is set,
+/// sent through the Core with the [Change] as the body, so the [Mediator]'s are able to
+/// react to the Model without the [Command]'s relaying it.
+///
+/// The mutations inside the [batch](ObservableProxy::batch) result in the single change,
+/// and the mutations leaving the Data equal to the old one result in no change at all.
+///
+/// [Mediator]: crate::prelude::Mediator
+/// [Command]: crate::prelude::Command
+pub struct ObservableProxy<Data, Body = Change<Data>> {
+    data: Lock<Data>,
+    interest: Option<Interest>,
+    listeners: Lock<Vec<(SubscriptionId, ChangeListener<Data>)>>,
+    next_id: Lock<SubscriptionId>,
+    batch: Lock<Batch<Data>>,
+
+    // Sends notifications through the Core the proxy is registered with
+    notifier: BaseNotifier,
+    body: PhantomData<fn() -> Body>,
+}
+
+impl<Data, Body> ObservableProxy<Data, Body>
+where
+    Data: Clone + PartialEq,
+    Body: From<Change<Data>> + fmt::Debug + MaybeSendSync + 'static,
+{
+    /// Create new ObservableProxy with the Data
+    pub fn new(data: Data) -> Self {
+        Self {
+            data: Lock::new(data),
+            interest: None,
+            listeners: Lock::new(Vec::new()),
+            next_id: Lock::new(0),
+            batch: Lock::new(Batch { depth: 0, old: None }),
+            notifier: BaseNotifier::new(),
+            body: PhantomData,
+        }
+    }
+
+    /// Send the Interest on each change, consuming the proxy
+    pub fn with_interest(mut self, interest: Interest) -> Self {
+        self.interest = Some(interest);
+        self
+    }
+
+    /// Retrieve the Data
+    pub fn get(&self) -> Data {
+        self.data.borrow().clone()
+    }
+
+    /// Read the Data
+    pub fn with<R>(&self, f: impl FnOnce(&Data) -> R) -> R {
+        f(&self.data.borrow())
+    }
+
+    /// Replace the Data
+    pub fn set(&self, data: Data) {
+        let old = self.data.replace(data);
+        self.changed(old);
+    }
+
+    /// Mutate the Data in place
+    pub fn update(&self, f: impl FnOnce(&mut Data)) {
+        let old = self.get();
+        f(&mut self.data.borrow_mut());
+        self.changed(old);
+    }
+
+    /// Collect the mutations made by the closure into the single change
+    pub fn batch(&self, f: impl FnOnce()) {
+        self.batch.borrow_mut().depth += 1;
+
+        f();
+
+        let old = {
+            let mut batch = self.batch.borrow_mut();
+            batch.depth -= 1;
+            if batch.depth == 0 {
+                batch.old.take()
+            } else {
+                None
+            }
+        };
+
+        if let Some(old) = old {
+            self.notify(old);
+        }
+    }
+
+    /// Subscribe to the changes of the Data
+    pub fn subscribe<F>(&self, listener: F) -> SubscriptionId
+    where
+        F: Fn(&Data, &Data) + MaybeSendSync + 'static,
+    {
+        let mut next_id = self.next_id.borrow_mut();
+        let id = *next_id;
+        *next_id += 1;
+
+        self.listeners.borrow_mut().push((id, Shared::new(listener)));
+        id
+    }
+
+    /// Subscribe to the changes of the field selected from the Data.
+    ///
+    /// The listener is called with the old and the new field, only if the field has changed.
+    pub fn subscribe_field<Field, S, F>(&self, selector: S, listener: F) -> SubscriptionId
+    where
+        Field: PartialEq,
+        S: Fn(&Data) -> Field + MaybeSendSync + 'static,
+        F: Fn(&Field, &Field) + MaybeSendSync + 'static,
+    {
+        self.subscribe(move |old, new| {
+            let (old, new) = (selector(old), selector(new));
+            if old != new {
+                listener(&old, &new);
+            }
+        })
+    }
+
+    /// Remove the subscription, returns `false` if it is not subscribed
+    pub fn unsubscribe(&self, id: SubscriptionId) -> bool {
+        let mut listeners = self.listeners.borrow_mut();
+        let len = listeners.len();
+        listeners.retain(|(subscription, _)| *subscription != id);
+        listeners.len() != len
+    }
+
+    fn changed(&self, old: Data) {
+        {
+            let mut batch = self.batch.borrow_mut();
+            if batch.depth > 0 {
+                batch.old.get_or_insert(old);
+                return;
+            }
+        }
+
+        self.notify(old);
+    }
+
+    // The data is not borrowed while the listeners are called,
+    // so they are able to read the proxy
+    fn notify(&self, old: Data) {
+        let new = self.get();
+        if old == new {
+            return;
+        }
+
+        let listeners = self.listeners.borrow().clone();
+        for (_, listener) in listeners.iter() {
+            listener(&old, &new);
+        }
+
+        if let Some(interest) = self.interest {
+            self.notifier.send(interest, Some(Body::from(Change { old, new })));
+        }
+    }
+}
+
+impl<Data, Body> Proxy for ObservableProxy<Data, Body>
+where
+    Data: fmt::Debug + MaybeSendSync + 'static,
+    Body: 'static,
+{
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
+    fn on_register(&self) {}
+
+    fn on_remove(&self) {}
+}
+
+impl<Data, Body, Other> Notifier<Other> for ObservableProxy<Data, Body>
+where
+    Other: fmt::Debug + MaybeSendSync + 'static,
+{
+    fn send(&self, interest: Interest, body: Option<Other>) {
+        self.notifier.send(interest, body);
+    }
+}
+
+impl<Data, Body> fmt::Debug for ObservableProxy<Data, Body>
+where
+    Data: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObservableProxy")
+            .field("data", &*self.data.borrow())
+            .field("interest", &self.interest)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    use super::{Change, ObservableProxy};
+
+    const PROFILE_CHANGED: Interest = Interest(1);
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Profile {
+        name: String,
+        age: u32,
+    }
+
+    // Records the changes of the profile
+    #[derive(Debug, Default)]
+    struct ProfileCommand {
+        changes: Shared<Lock<Vec<(u32, u32)>>>,
+    }
+
+    impl Command<Change<Profile>> for ProfileCommand {
+        fn execute(&self, notification: Shared<dyn Notification<Change<Profile>>>) {
+            if let Some(change) = notification.body() {
+                self.changes.borrow_mut().push((change.old.age, change.new.age));
+            }
+        }
+    }
+
+    #[test]
+    fn should_notify_changes_of_data() {
+        let facade = BaseFacade::<Change<Profile>>::instance("observable-proxy");
+
+        let command = Shared::new(ProfileCommand::default());
+        let changes = command.changes.clone();
+        facade.register_command(PROFILE_CHANGED, command);
+
+        let proxy = ObservableProxy::<Profile>::new(Profile::default()).with_interest(PROFILE_CHANGED);
+        facade.register_proxy(Shared::new(proxy));
+        let proxy = facade.retrieve_proxy::<ObservableProxy<Profile>>().unwrap();
+
+        let names = Shared::new(Lock::new(Vec::new()));
+        let listener = names.clone();
+        let id = proxy.subscribe_field(
+            |profile| profile.name.clone(),
+            move |_, name: &String| listener.borrow_mut().push(name.clone()),
+        );
+
+        proxy.update(|profile| profile.age = 1);
+        proxy.set(Profile {
+            name: "Alice".into(),
+            age: 1,
+        });
+
+        // the mutations of the batch are sent once, the unchanged data is not sent
+        proxy.batch(|| {
+            proxy.update(|profile| profile.age = 2);
+            proxy.update(|profile| profile.age = 3);
+        });
+        proxy.update(|profile| profile.age = 3);
+
+        assert_eq!(*changes.borrow(), vec![(0, 1), (1, 1), (1, 3)]);
+        assert_eq!(*names.borrow(), vec!["Alice".to_string()]);
+
+        assert!(proxy.unsubscribe(id));
+        proxy.update(|profile| profile.name = "Bob".into());
+        assert_eq!(names.borrow().len(), 1);
+
+        BaseFacade::<Change<Profile>>::remove_core("observable-proxy");
+    }
+}
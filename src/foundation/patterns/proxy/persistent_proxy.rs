@@ -0,0 +1,376 @@
+use std::{error::Error, fmt, io};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{CoreKey, Interest, Lock, MaybeSendSync, Notifier, Proxy, Shared, Storage},
+};
+
+/// Represents the failure to load or save the [PersistentProxy]
+#[derive(Debug)]
+pub enum PersistError {
+    /// The storage has failed
+    Io(io::Error),
+
+    /// The document is not valid for the Data
+    Format(serde_json::Error),
+
+    /// The document has the version the proxy is not able to migrate from
+    Version {
+        /// The version of the stored document
+        found: u32,
+        /// The version of the proxy
+        expected: u32,
+    },
+}
+
+impl fmt::Display for PersistError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "The storage has failed: {}", err),
+            Self::Format(err) => write!(f, "The document is malformed: {}", err),
+            Self::Version { found, expected } => {
+                write!(f, "Unable to migrate the document from version {} to {}", found, expected)
+            }
+        }
+    }
+}
+
+impl Error for PersistError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Format(err) => Some(err),
+            Self::Version { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for PersistError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for PersistError {
+    fn from(err: serde_json::Error) -> Self {
+        Self::Format(err)
+    }
+}
+
+/// Upgrades the stored Data of the older version to the current one,
+/// called with the version of the document and its Data
+#[cfg(not(feature = "sync"))]
+pub type Migration = Box<dyn Fn(u32, Value) -> Result<Value, PersistError>>;
+
+/// Upgrades the stored Data of the older version to the current one,
+/// called with the version of the document and its Data
+#[cfg(feature = "sync")]
+pub type Migration = Box<dyn Fn(u32, Value) -> Result<Value, PersistError> + Send + Sync>;
+
+// The document being saved
+#[derive(Serialize)]
+struct Document<'a, Data> {
+    version: u32,
+    data: &'a Data,
+}
+
+// The document being loaded, the data is deserialized after the migration
+#[derive(Deserialize)]
+struct StoredDocument {
+    version: u32,
+    data: Value,
+}
+
+/// A [Proxy] which keeps its Data in the [Storage].
+///
+/// The Data is loaded when the proxy is registered with the Model, and saved on each change,
+/// or, with the autosave turned off, on the explicit [flush](PersistentProxy::flush).
+/// The pending changes are flushed when the proxy is removed.
+///
+/// The Data is stored as the JSON document along with the version of its schema.
+/// The document of the older version is passed to the migration before it is deserialized.
+pub struct PersistentProxy<Data> {
+    key: String,
+    version: u32,
+    data: Lock<Data>,
+    storage: Shared<dyn Storage>,
+    migration: Option<Migration>,
+    autosave: bool,
+    dirty: Lock<bool>,
+
+    // Sends notifications through the Core the proxy is registered with
+    notifier: BaseNotifier,
+}
+
+impl<Data> PersistentProxy<Data>
+where
+    Data: Serialize + DeserializeOwned,
+{
+    /// Create new PersistentProxy storing the Data with the key,
+    /// the Data is used until the stored one is loaded
+    pub fn new(key: impl Into<String>, data: Data, storage: Shared<dyn Storage>) -> Self {
+        Self {
+            key: key.into(),
+            version: 0,
+            data: Lock::new(data),
+            storage,
+            migration: None,
+            autosave: true,
+            dirty: Lock::new(false),
+            notifier: BaseNotifier::new(),
+        }
+    }
+
+    /// Set the version of the Data schema, consuming the proxy
+    pub fn with_version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Set the migration of the older documents, consuming the proxy
+    pub fn with_migration<F>(mut self, migration: F) -> Self
+    where
+        F: Fn(u32, Value) -> Result<Value, PersistError> + MaybeSendSync + 'static,
+    {
+        self.migration = Some(Box::new(migration));
+        self
+    }
+
+    /// Turn saving on each change on or off, consuming the proxy
+    pub fn with_autosave(mut self, autosave: bool) -> Self {
+        self.autosave = autosave;
+        self
+    }
+
+    /// Retrieve the key of the stored Data
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Retrieve the version of the Data schema
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Retrieve the Data
+    pub fn get(&self) -> Data
+    where
+        Data: Clone,
+    {
+        self.data.borrow().clone()
+    }
+
+    /// Read the Data
+    pub fn with<R>(&self, f: impl FnOnce(&Data) -> R) -> R {
+        f(&self.data.borrow())
+    }
+
+    /// Replace the Data, saving it with the autosave on
+    pub fn set(&self, data: Data) -> Result<(), PersistError> {
+        *self.data.borrow_mut() = data;
+        self.changed()
+    }
+
+    /// Mutate the Data in place, saving it with the autosave on
+    pub fn update(&self, f: impl FnOnce(&mut Data)) -> Result<(), PersistError> {
+        f(&mut self.data.borrow_mut());
+        self.changed()
+    }
+
+    /// Check whether the Data has changes which are not saved
+    pub fn is_dirty(&self) -> bool {
+        *self.dirty.borrow()
+    }
+
+    /// Load the stored Data, returns `false` if there is nothing stored.
+    ///
+    /// The migrated Data is marked as changed, so the next save stores it with the current version.
+    pub fn load(&self) -> Result<bool, PersistError> {
+        let document = match self.storage.load(&self.key)? {
+            Some(document) => document,
+            None => return Ok(false),
+        };
+
+        let StoredDocument { version, mut data } = serde_json::from_str(&document)?;
+        if version != self.version {
+            data = match &self.migration {
+                Some(migration) if version < self.version => migration(version, data)?,
+                _ => {
+                    return Err(PersistError::Version {
+                        found: version,
+                        expected: self.version,
+                    })
+                }
+            };
+        }
+
+        *self.data.borrow_mut() = serde_json::from_value(data)?;
+        *self.dirty.borrow_mut() = version != self.version;
+        Ok(true)
+    }
+
+    /// Save the Data to the storage
+    pub fn save(&self) -> Result<(), PersistError> {
+        let document = serde_json::to_string_pretty(&Document {
+            version: self.version,
+            data: &*self.data.borrow(),
+        })?;
+
+        self.storage.save(&self.key, &document)?;
+        *self.dirty.borrow_mut() = false;
+        Ok(())
+    }
+
+    /// Save the Data if it has changes which are not saved
+    pub fn flush(&self) -> Result<(), PersistError> {
+        if self.is_dirty() {
+            self.save()?;
+        }
+        Ok(())
+    }
+
+    fn changed(&self) -> Result<(), PersistError> {
+        *self.dirty.borrow_mut() = true;
+        if self.autosave {
+            self.save()?;
+        }
+        Ok(())
+    }
+}
+
+impl<Data> Proxy for PersistentProxy<Data>
+where
+    Data: Serialize + DeserializeOwned + fmt::Debug + MaybeSendSync + 'static,
+{
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
+    fn on_register(&self) {
+        if let Err(err) = self.load() {
+            log::error!("Load {} [PersistentProxy] {}", self.key, err);
+        }
+    }
+
+    fn on_remove(&self) {
+        if let Err(err) = self.flush() {
+            log::error!("Flush {} [PersistentProxy] {}", self.key, err);
+        }
+    }
+}
+
+impl<Data, Body> Notifier<Body> for PersistentProxy<Data>
+where
+    Body: fmt::Debug + MaybeSendSync + 'static,
+{
+    fn send(&self, interest: Interest, body: Option<Body>) {
+        self.notifier.send(interest, body);
+    }
+}
+
+impl<Data> fmt::Debug for PersistentProxy<Data>
+where
+    Data: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PersistentProxy")
+            .field("key", &self.key)
+            .field("version", &self.version)
+            .field("data", &*self.data.borrow())
+            .field("dirty", &*self.dirty.borrow())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+    use serde_json::json;
+
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    use super::{super::JsonFileStorage, super::MemoryStorage, PersistError, PersistentProxy};
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+    struct Settings {
+        theme: String,
+        font_size: u32,
+    }
+
+    #[test]
+    fn should_load_and_migrate_stored_data() -> Result<(), PersistError> {
+        let storage = Shared::new(MemoryStorage::new());
+        storage.save("settings", r#"{ "version": 1, "data": { "theme": "dark", "size": 12 } }"#)?;
+
+        let facade = BaseFacade::<()>::instance("persistent-proxy");
+
+        // the version 1 named the font size just `size`
+        let proxy = PersistentProxy::new("settings", Settings::default(), storage.clone())
+            .with_version(2)
+            .with_migration(|_, mut data| {
+                data["font_size"] = data["size"].take();
+                Ok(data)
+            })
+            .with_autosave(false);
+        facade.register_proxy(Shared::new(proxy));
+        let proxy = facade.retrieve_proxy::<PersistentProxy<Settings>>().unwrap();
+
+        assert_eq!(
+            proxy.get(),
+            Settings {
+                theme: "dark".into(),
+                font_size: 12
+            }
+        );
+        assert!(proxy.is_dirty());
+
+        proxy.update(|settings| settings.font_size = 14)?;
+        proxy.flush()?;
+        assert!(!proxy.is_dirty());
+
+        let stored: serde_json::Value = serde_json::from_str(&storage.load("settings")?.unwrap())?;
+        assert_eq!(stored, json!({ "version": 2, "data": { "theme": "dark", "font_size": 14 } }));
+
+        // the newer document is not loaded
+        let proxy = PersistentProxy::new("settings", Settings::default(), storage).with_version(1);
+        assert!(matches!(proxy.load(), Err(PersistError::Version { found: 2, expected: 1 })));
+
+        BaseFacade::<()>::remove_core("persistent-proxy");
+        Ok(())
+    }
+
+    #[test]
+    fn should_save_data_to_json_file() -> Result<(), PersistError> {
+        let dir = std::env::temp_dir().join(format!("ruex-persistent-proxy-{}", std::process::id()));
+        let storage = Shared::new(JsonFileStorage::new(&dir));
+
+        let proxy = PersistentProxy::new("settings", Settings::default(), storage.clone());
+        assert!(!proxy.load()?);
+
+        proxy.set(Settings {
+            theme: "light".into(),
+            font_size: 10,
+        })?;
+        assert!(storage.path("settings")?.exists());
+
+        let proxy = PersistentProxy::new("settings", Settings::default(), storage.clone());
+        assert!(proxy.load()?);
+        assert_eq!(proxy.with(|settings| settings.font_size), 10);
+
+        storage.remove("settings")?;
+        assert!(!proxy.load()?);
+
+        // the keys are not able to refer outside of the directory
+        for key in ["../settings", "/etc/settings", "nested/settings", ""] {
+            let err = storage.save(key, "{}").unwrap_err();
+            assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+        }
+        assert!(!dir.join("..").join("settings.json").exists());
+
+        std::fs::remove_dir_all(dir)?;
+        Ok(())
+    }
+}
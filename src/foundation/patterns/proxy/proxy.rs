@@ -1,8 +1,8 @@
 use std::fmt;
 
 use crate::{
-    foundation::patterns::facade::BaseFacade,
-    prelude::{Facade, Interest, Notifier, Proxy, Singleton},
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{CoreKey, Interest, MaybeSendSync, Notifier, Proxy},
 };
 
 /// A base [Proxy] implementation.
@@ -21,13 +21,15 @@ use crate::{
 /// when the [Proxy] has retrieved the data from the service.
 /// 
 /// [Notification]: crate::prelude::Notification
-
 pub struct BaseProxy<Body>
 where
     Body: fmt::Debug + 'static,
 {
     /// Represens data object
     pub data: Option<Body>,
+
+    // Sends notifications through the Core the proxy is registered with
+    notifier: BaseNotifier,
 }
 
 impl<Body> BaseProxy<Body>
@@ -36,14 +38,21 @@ where
 {
     /// Constructor
     pub fn new(data: Option<Body>) -> Self {
-        Self { data }
+        Self {
+            data,
+            notifier: BaseNotifier::new(),
+        }
     }
 }
 
 impl<Body> Proxy for BaseProxy<Body>
 where
-    Body: fmt::Debug + 'static,
+    Body: fmt::Debug + MaybeSendSync + 'static,
 {
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
     fn on_register(&self) {}
 
     fn on_remove(&self) {}
@@ -51,11 +60,10 @@ where
 
 impl<Body> Notifier<Body> for BaseProxy<Body>
 where
-    Body: fmt::Debug + 'static,
+    Body: fmt::Debug + MaybeSendSync + 'static,
 {
     fn send(&self, interest: Interest, body: Option<Body>) {
-        log::error!("You should implement yourself Proxy");
-        BaseFacade::<Body>::global().send(interest, body);
+        self.notifier.send(interest, body);
     }
 }
 
@@ -0,0 +1,101 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::prelude::{Lock, Storage};
+
+/// The [Storage] keeping the documents in memory, useful for the tests
+/// and the data which should not outlive the application.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    documents: Lock<HashMap<String, String>>,
+}
+
+impl MemoryStorage {
+    /// Create new empty MemoryStorage
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    fn load(&self, key: &str) -> io::Result<Option<String>> {
+        Ok(self.documents.borrow().get(key).cloned())
+    }
+
+    fn save(&self, key: &str, document: &str) -> io::Result<()> {
+        self.documents.borrow_mut().insert(key.into(), document.into());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        self.documents.borrow_mut().remove(key);
+        Ok(())
+    }
+}
+
+/// The [Storage] keeping each document in the `<key>.json` file of the directory.
+///
+/// The directory is created on the first save. The document is written to the temporary
+/// file first and then renamed, so the crash while saving leaves the previous document intact.
+///
+/// The key is the name of the file, so the keys with the path separators or `..`
+/// are rejected with [InvalidInput](io::ErrorKind::InvalidInput), they would refer
+/// to the files outside of the directory.
+#[derive(Debug, Clone)]
+pub struct JsonFileStorage {
+    dir: PathBuf,
+}
+
+impl JsonFileStorage {
+    /// Create new JsonFileStorage in the directory
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Retrieve the path of the file keeping the document
+    pub fn path(&self, key: &str) -> io::Result<PathBuf> {
+        let invalid = key.is_empty()
+            || key.contains("..")
+            || key.chars().any(|c| std::path::is_separator(c) || c == ':' || c == '\0');
+
+        if invalid {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("The key {:?} is not a valid file name", key),
+            ));
+        }
+
+        Ok(self.dir.join(format!("{}.json", key)))
+    }
+}
+
+impl Storage for JsonFileStorage {
+    fn load(&self, key: &str) -> io::Result<Option<String>> {
+        match fs::read_to_string(self.path(key)?) {
+            Ok(document) => Ok(Some(document)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    fn save(&self, key: &str, document: &str) -> io::Result<()> {
+        let path = self.path(key)?;
+        fs::create_dir_all(&self.dir)?;
+
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, document)?;
+        fs::rename(&temp, path)
+    }
+
+    fn remove(&self, key: &str) -> io::Result<()> {
+        match fs::remove_file(self.path(key)?) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+}
@@ -0,0 +1,22 @@
+//! Time-travel state Store
+//!
+//! The Store keeps the state of the application as the result of the
+//! Actions dispatched to it. Each Action is reduced to a new State by the
+//! Reducers of the Store, and recorded to its action log.
+//!
+//! As the State is derived from the initial State and the action log only,
+//! the Store is able to jump to any past Action, step backwards and forwards,
+//! and replay the whole log, e.g. after the Reducers have been changed.
+//! The log is exported and imported through serde, so a session is able
+//! to be saved and loaded back while debugging.
+//!
+//! The StoreProxy registers the Store with the Model, so the Commands and
+//! Mediators access the state of the application as any other Proxy,
+//! and subscribe to its changes.
+
+#[allow(clippy::module_inception)]
+mod store;
+pub use self::store::*;
+
+mod store_proxy;
+pub use self::store_proxy::*;
@@ -0,0 +1,254 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::prelude::MaybeSendSync;
+
+/// Reduces the State and the dispatched Action to the new State
+#[cfg(not(feature = "sync"))]
+pub type Reducer<State, Action> = Box<dyn Fn(&State, &Action) -> State>;
+
+/// Reduces the State and the dispatched Action to the new State
+#[cfg(feature = "sync")]
+pub type Reducer<State, Action> = Box<dyn Fn(&State, &Action) -> State + Send + Sync>;
+
+/// The serializable action log of the [Store].
+///
+/// Contains everything to restore the [Store] with its Reducers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoreLog<State, Action> {
+    /// The State the Actions are applied to
+    pub initial: State,
+
+    /// The dispatched Actions
+    pub actions: Vec<Action>,
+
+    /// The number of the Actions the current State is reduced from
+    pub cursor: usize,
+}
+
+/// A time-travel state Store.
+///
+/// The current State is reduced from the initial State and the first
+/// [cursor](Store::cursor) Actions of the log. Jumping to a past Action
+/// keeps the log, so it is possible to step forward again, until a new
+/// Action is dispatched, which drops the Actions after the cursor.
+pub struct Store<State, Action> {
+    initial: State,
+    state: State,
+    reducers: Vec<Reducer<State, Action>>,
+    actions: Vec<Action>,
+    cursor: usize,
+}
+
+impl<State, Action> Store<State, Action>
+where
+    State: Clone,
+{
+    /// Create new Store with the initial State
+    pub fn new(initial: State) -> Self {
+        Self {
+            state: initial.clone(),
+            initial,
+            reducers: Vec::new(),
+            actions: Vec::new(),
+            cursor: 0,
+        }
+    }
+
+    /// Add the Reducer to the Store, consuming it
+    pub fn with_reducer<F>(mut self, reducer: F) -> Self
+    where
+        F: Fn(&State, &Action) -> State + MaybeSendSync + 'static,
+    {
+        self.add_reducer(reducer);
+        self
+    }
+
+    /// Add the Reducer to the Store.
+    ///
+    /// The Reducers are applied in the order they are added,
+    /// each one receives the State reduced by the previous one.
+    /// The current State is not replayed, use [replay](Store::replay) for it.
+    pub fn add_reducer<F>(&mut self, reducer: F)
+    where
+        F: Fn(&State, &Action) -> State + MaybeSendSync + 'static,
+    {
+        self.reducers.push(Box::new(reducer));
+    }
+
+    /// Retrieve the current State
+    pub fn state(&self) -> &State {
+        &self.state
+    }
+
+    /// Retrieve the initial State
+    pub fn initial(&self) -> &State {
+        &self.initial
+    }
+
+    /// Retrieve the action log, including the Actions after the cursor
+    pub fn actions(&self) -> &[Action] {
+        &self.actions
+    }
+
+    /// Retrieve the number of the Actions the current State is reduced from
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+
+    /// Dispatch the Action, returns the new State.
+    ///
+    /// The Actions after the cursor are dropped.
+    pub fn dispatch(&mut self, action: Action) -> &State {
+        self.actions.truncate(self.cursor);
+        self.state = self.reduce(&self.state, &action);
+        self.actions.push(action);
+        self.cursor = self.actions.len();
+        &self.state
+    }
+
+    /// Jump to the State reduced from the first `cursor` Actions of the log.
+    ///
+    /// Returns `false` if the log has fewer Actions.
+    pub fn jump_to(&mut self, cursor: usize) -> bool {
+        if cursor > self.actions.len() {
+            return false;
+        }
+
+        // going forward continues from the current State
+        let (mut state, start) = if cursor >= self.cursor {
+            (self.state.clone(), self.cursor)
+        } else {
+            (self.initial.clone(), 0)
+        };
+
+        for action in &self.actions[start..cursor] {
+            state = self.reduce(&state, action);
+        }
+
+        self.state = state;
+        self.cursor = cursor;
+        true
+    }
+
+    /// Step one Action backwards, returns `false` at the initial State
+    pub fn step_back(&mut self) -> bool {
+        self.cursor > 0 && self.jump_to(self.cursor - 1)
+    }
+
+    /// Step one Action forward, returns `false` at the last Action of the log
+    pub fn step_forward(&mut self) -> bool {
+        self.jump_to(self.cursor + 1)
+    }
+
+    /// Reduce the current State from the initial State again
+    pub fn replay(&mut self) {
+        let cursor = self.cursor;
+        self.cursor = 0;
+        self.state = self.initial.clone();
+        self.jump_to(cursor);
+    }
+
+    /// Export the action log
+    pub fn export(&self) -> StoreLog<State, Action>
+    where
+        Action: Clone,
+    {
+        StoreLog {
+            initial: self.initial.clone(),
+            actions: self.actions.clone(),
+            cursor: self.cursor,
+        }
+    }
+
+    /// Import the action log, the current State is replayed with the Reducers of the Store.
+    ///
+    /// The cursor is limited by the number of the imported Actions.
+    pub fn import(&mut self, log: StoreLog<State, Action>) {
+        self.cursor = log.cursor.min(log.actions.len());
+        self.initial = log.initial;
+        self.actions = log.actions;
+        self.replay();
+    }
+
+    fn reduce(&self, state: &State, action: &Action) -> State {
+        self.reducers
+            .iter()
+            .fold(state.clone(), |state, reducer| reducer(&state, action))
+    }
+}
+
+impl<State, Action> fmt::Debug for Store<State, Action>
+where
+    State: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Store")
+            .field("state", &self.state)
+            .field("actions", &self.actions.len())
+            .field("cursor", &self.cursor)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::{Deserialize, Serialize};
+
+    use super::Store;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum Counter {
+        Add(i32),
+        Reset,
+    }
+
+    fn counter() -> Store<i32, Counter> {
+        Store::new(0).with_reducer(|state, action| match action {
+            Counter::Add(amount) => state + amount,
+            Counter::Reset => 0,
+        })
+    }
+
+    #[test]
+    fn should_travel_through_action_log() {
+        let mut store = counter();
+
+        store.dispatch(Counter::Add(2));
+        store.dispatch(Counter::Add(3));
+        store.dispatch(Counter::Reset);
+        assert_eq!(*store.dispatch(Counter::Add(1)), 1);
+
+        assert!(store.jump_to(2));
+        assert_eq!(*store.state(), 5);
+        assert!(store.step_back());
+        assert_eq!(*store.state(), 2);
+        assert!(store.step_forward());
+        assert_eq!(*store.state(), 5);
+        assert!(!store.jump_to(5));
+
+        // the actions after the cursor are dropped
+        store.dispatch(Counter::Add(10));
+        assert_eq!(store.actions(), &[Counter::Add(2), Counter::Add(3), Counter::Add(10)]);
+        assert_eq!(*store.state(), 15);
+    }
+
+    #[test]
+    fn should_replay_imported_log() {
+        let mut store = counter();
+        store.dispatch(Counter::Add(2));
+        store.dispatch(Counter::Add(3));
+        store.step_back();
+
+        let json = serde_json::to_string(&store.export()).unwrap();
+
+        let mut restored = counter();
+        restored.import(serde_json::from_str(&json).unwrap());
+
+        assert_eq!(*restored.state(), 2);
+        assert_eq!(restored.cursor(), 1);
+        assert!(restored.step_forward());
+        assert_eq!(*restored.state(), 5);
+    }
+}
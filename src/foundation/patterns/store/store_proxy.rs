@@ -0,0 +1,197 @@
+use std::fmt;
+
+use crate::{
+    foundation::patterns::observer::BaseNotifier,
+    prelude::{CoreKey, Interest, Lock, MaybeSendSync, Notifier, Proxy, Shared},
+};
+
+use super::{Store, StoreLog};
+
+/// Listens to the State changes of the [StoreProxy]
+#[cfg(not(feature = "sync"))]
+pub type StateListener<State> = Shared<dyn Fn(&State)>;
+
+/// Listens to the State changes of the [StoreProxy]
+#[cfg(feature = "sync")]
+pub type StateListener<State> = Shared<dyn Fn(&State) + Send + Sync>;
+
+/// A [Proxy] which exposes the [Store] to the Core.
+///
+/// Register it with the [Model] to access the state of the application
+/// from the [Command]'s and [Mediator]'s. The listeners are called with the
+/// new State each time the State is changed, including the time travel.
+///
+/// [Model]: crate::prelude::Model
+/// [Command]: crate::prelude::Command
+/// [Mediator]: crate::prelude::Mediator
+pub struct StoreProxy<State, Action> {
+    store: Lock<Store<State, Action>>,
+    listeners: Lock<Vec<StateListener<State>>>,
+
+    // Sends notifications through the Core the proxy is registered with
+    notifier: BaseNotifier,
+}
+
+impl<State, Action> StoreProxy<State, Action>
+where
+    State: Clone,
+{
+    /// Create new StoreProxy for the Store
+    pub fn new(store: Store<State, Action>) -> Self {
+        Self {
+            store: Lock::new(store),
+            listeners: Lock::new(Vec::new()),
+            notifier: BaseNotifier::new(),
+        }
+    }
+
+    /// Retrieve the current State
+    pub fn state(&self) -> State {
+        self.store.borrow().state().clone()
+    }
+
+    /// Retrieve the number of the Actions the current State is reduced from
+    pub fn cursor(&self) -> usize {
+        self.store.borrow().cursor()
+    }
+
+    /// Read the [Store]
+    pub fn with_store<R>(&self, f: impl FnOnce(&Store<State, Action>) -> R) -> R {
+        f(&self.store.borrow())
+    }
+
+    /// Subscribe to the State changes
+    pub fn subscribe<F>(&self, listener: F)
+    where
+        F: Fn(&State) + MaybeSendSync + 'static,
+    {
+        self.listeners.borrow_mut().push(Shared::new(listener));
+    }
+
+    /// Dispatch the Action to the [Store]
+    pub fn dispatch(&self, action: Action) {
+        self.store.borrow_mut().dispatch(action);
+        self.changed();
+    }
+
+    /// Jump to the State reduced from the first `cursor` Actions of the log.
+    ///
+    /// Returns `false` if the log has fewer Actions.
+    pub fn jump_to(&self, cursor: usize) -> bool {
+        let jumped = self.store.borrow_mut().jump_to(cursor);
+        if jumped {
+            self.changed();
+        }
+        jumped
+    }
+
+    /// Step one Action backwards, returns `false` at the initial State
+    pub fn step_back(&self) -> bool {
+        let stepped = self.store.borrow_mut().step_back();
+        if stepped {
+            self.changed();
+        }
+        stepped
+    }
+
+    /// Step one Action forward, returns `false` at the last Action of the log
+    pub fn step_forward(&self) -> bool {
+        let stepped = self.store.borrow_mut().step_forward();
+        if stepped {
+            self.changed();
+        }
+        stepped
+    }
+
+    /// Export the action log of the [Store]
+    pub fn export(&self) -> StoreLog<State, Action>
+    where
+        Action: Clone,
+    {
+        self.store.borrow().export()
+    }
+
+    /// Import the action log to the [Store]
+    pub fn import(&self, log: StoreLog<State, Action>) {
+        self.store.borrow_mut().import(log);
+        self.changed();
+    }
+
+    // The store is not borrowed while the listeners are called,
+    // so they are able to read the proxy
+    fn changed(&self) {
+        let state = self.state();
+        let listeners = self.listeners.borrow().clone();
+
+        for listener in listeners.iter() {
+            listener(&state);
+        }
+    }
+}
+
+impl<State, Action> Proxy for StoreProxy<State, Action>
+where
+    State: fmt::Debug + MaybeSendSync + 'static,
+    Action: MaybeSendSync + 'static,
+{
+    fn initialize_notifier(&self, key: &CoreKey) {
+        self.notifier.initialize_notifier(key);
+    }
+
+    fn on_register(&self) {}
+
+    fn on_remove(&self) {}
+}
+
+impl<State, Action, Body> Notifier<Body> for StoreProxy<State, Action>
+where
+    Body: fmt::Debug + MaybeSendSync + 'static,
+{
+    fn send(&self, interest: Interest, body: Option<Body>) {
+        self.notifier.send(interest, body);
+    }
+}
+
+impl<State, Action> fmt::Debug for StoreProxy<State, Action>
+where
+    State: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StoreProxy").field("store", &*self.store.borrow()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicI32, Ordering};
+
+    use crate::{foundation::patterns::facade::BaseFacade, prelude::*};
+
+    use super::{Store, StoreProxy};
+
+    #[derive(Debug)]
+    struct Add(i32);
+
+    #[test]
+    fn should_expose_store_to_core() {
+        let facade = BaseFacade::<u32>::instance("store-proxy");
+
+        let store = Store::new(0).with_reducer(|state: &i32, action: &Add| state + action.0);
+        facade.register_proxy(Shared::new(StoreProxy::new(store)));
+
+        let proxy = facade.retrieve_proxy::<StoreProxy<i32, Add>>().unwrap();
+        let observed = Shared::new(AtomicI32::new(0));
+        let listener = observed.clone();
+        proxy.subscribe(move |state| listener.store(*state, Ordering::SeqCst));
+
+        proxy.dispatch(Add(2));
+        proxy.dispatch(Add(3));
+        assert_eq!(observed.load(Ordering::SeqCst), 5);
+
+        assert!(proxy.step_back());
+        assert_eq!(observed.load(Ordering::SeqCst), 2);
+        assert_eq!(proxy.state(), 2);
+
+        BaseFacade::<u32>::remove_core("store-proxy");
+    }
+}
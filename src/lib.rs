@@ -13,11 +13,11 @@
 //! importance in the building of scalable and maintainable applications.
 //! 
 //! In this implementation of the classic MVC Design meta-pattern, these
-//! three tiers of the application are governed by three Singletons (a class
-//! where only one instance may be created) called simply [Model][2], [View][3]
+//! three tiers of the application are governed by three Multitons (a class
+//! where only one instance may be created per Core key) called simply [Model][2], [View][3]
 //! and [Controller][1]. Together, they are referred to as the ‘Core actors’.
 //! 
-//! A fourth Singleton, the [Facade][4] simplifies development by providing a
+//! A fourth Multiton, the [Facade][4] simplifies development by providing a
 //! single interface for communication with the Core actors.
 //! 
 //! [Read more..][foundation]
@@ -1,12 +1,80 @@
-use std::{fmt::Debug, rc::Rc};
+use std::fmt::Debug;
 
-use super::Notification;
+use super::{BoxFuture, CoreKey, MaybeSendSync, Notification, Shared};
 
 /// The definition for a PureMVC Command.
-pub trait Command<Body>: Debug
+pub trait Command<Body>: Debug + MaybeSendSync
 where
     Body: Debug + 'static,
 {
+    /// Initialize the [Command] with the multiton key of the Core.
+    ///
+    /// Called by the [Controller] when the [Command] is registered,
+    /// so the [Command] is able to send [Notification]'s through its Core.
+    ///
+    /// [Controller]: crate::prelude::Controller
+    fn initialize_notifier(&self, _key: &CoreKey) {}
+
     /// Execute the [Command]'s logic to handle a given [Notification].
-    fn execute(&self, notification: Rc<dyn Notification<Body>>);
+    fn execute(&self, notification: Shared<dyn Notification<Body>>);
+}
+
+/// The definition for an asynchronous PureMVC Command.
+///
+/// Unlike the [Command], the [AsyncCommand] does not block the notification loop,
+/// its [execute](AsyncCommand::execute) method only creates the future of the work.
+/// The future is driven by the [Spawner] of the [Controller], or awaited
+/// by the caller of [send_async].
+///
+/// [Controller]: crate::prelude::Controller
+/// [Spawner]: crate::prelude::Spawner
+/// [send_async]: crate::prelude::Facade::send_async
+pub trait AsyncCommand<Body>: Debug + MaybeSendSync
+where
+    Body: Debug + 'static,
+{
+    /// Initialize the [AsyncCommand] with the multiton key of the Core.
+    ///
+    /// Called by the [Controller] when the [AsyncCommand] is registered,
+    /// so the [AsyncCommand] is able to send [Notification]'s through its Core.
+    ///
+    /// [Controller]: crate::prelude::Controller
+    fn initialize_notifier(&self, _key: &CoreKey) {}
+
+    /// Create the future of the [AsyncCommand]'s logic to handle a given [Notification].
+    fn execute(&self, notification: Shared<dyn Notification<Body>>) -> BoxFuture<'static, ()>;
+}
+
+/// The definition for an undoable PureMVC Command.
+///
+/// The [UndoableCommand]'s executed by the [Controller] are recorded by its
+/// [CommandHistory], together with the [Notification] they have handled,
+/// so they are able to be undone and redone later.
+///
+/// [Controller]: crate::prelude::Controller
+/// [CommandHistory]: crate::foundation::patterns::command::CommandHistory
+pub trait UndoableCommand<Body>: Debug + MaybeSendSync
+where
+    Body: Debug + 'static,
+{
+    /// Initialize the [UndoableCommand] with the multiton key of the Core.
+    ///
+    /// Called by the [Controller] when the [UndoableCommand] is registered,
+    /// so the [UndoableCommand] is able to send [Notification]'s through its Core.
+    ///
+    /// [Controller]: crate::prelude::Controller
+    fn initialize_notifier(&self, _key: &CoreKey) {}
+
+    /// Execute the [UndoableCommand]'s logic to handle a given [Notification].
+    fn execute(&self, notification: Shared<dyn Notification<Body>>);
+
+    /// Revert the changes made by the execution for a given [Notification].
+    fn undo(&self, notification: Shared<dyn Notification<Body>>);
+
+    /// Apply the reverted changes for a given [Notification] again.
+    ///
+    /// Executes the [UndoableCommand] by default.
+    fn redo(&self, notification: Shared<dyn Notification<Body>>) {
+        self.execute(notification);
+    }
 }
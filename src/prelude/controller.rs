@@ -1,6 +1,6 @@
-use std::{fmt::Debug, rc::Rc};
+use std::fmt::Debug;
 
-use super::{Command, Interest, Notification};
+use super::{AsyncCommand, Command, Interest, Notification, Shared, UndoableCommand};
 
 /// The definition for a PureMVC Controller.
 ///
@@ -9,30 +9,41 @@ use super::{Command, Interest, Notification};
 /// assumes these responsibilities:
 ///
 /// - Remembering which [Command]'s
-/// are intended to handle which [Notification]'s
+///   are intended to handle which [Notification]'s
 /// - Registering itself as an [Observer] with
-/// the [View] for each [Notification]
-/// that it has an [Command] mapping for
+///   the [View] for each [Notification]
+///   that it has an [Command] mapping for
 /// - Creating a new instance of the proper [Command]
-/// to handle a given [Notification] when notified by the [View]
+///   to handle a given [Notification] when notified by the [View]
 /// - Calling the [Command]'s [execute]
-/// method, passing in the [Notification]
+///   method, passing in the [Notification]
 ///
 /// [Observer]: crate::prelude::Observer
 /// [View]: crate::prelude::View
 /// [execute]: Command::execute
-
 pub trait Controller<Body>
 where
     Body: Debug + 'static,
 {
     /// Register a particular [Command] class as the handler
     /// for a particular [Notification].
-    fn register_command(&self, interest: Interest, command: Rc<dyn Command<Body>>);
+    fn register_command(&self, interest: Interest, command: Shared<dyn Command<Body>>);
+
+    /// Register a particular [AsyncCommand] class as the handler
+    /// for a particular [Notification].
+    ///
+    /// Only one [Command] of any kind is registered for a [Notification].
+    fn register_async_command(&self, interest: Interest, command: Shared<dyn AsyncCommand<Body>>);
+
+    /// Register a particular [UndoableCommand] class as the handler
+    /// for a particular [Notification].
+    ///
+    /// Each execution of the [UndoableCommand] is recorded to the undo history.
+    fn register_undoable_command(&self, interest: Interest, command: Shared<dyn UndoableCommand<Body>>);
 
     /// Execute the [Command] previously registered as the
     /// handler for [Notification]'s with the given notification name.
-    fn execute_command(&self, notification: Rc<dyn Notification<Body>>);
+    fn execute_command(&self, notification: Shared<dyn Notification<Body>>);
 
     /// Remove a previously registered [Command] to [Notification] mapping.
     fn remove_command(&self, interest: &Interest);
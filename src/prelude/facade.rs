@@ -1,6 +1,6 @@
-use std::{fmt::Debug, rc::Rc};
+use std::fmt::Debug;
 
-use super::{Command, Interest};
+use super::{AsyncCommand, BoxFuture, Command, Interest, Middleware, Shared, UndoableCommand};
 
 /// The definition for a PureMVC Facade.
 ///
@@ -25,7 +25,17 @@ where
     /// Register an [Command] with the [Controller].
     /// 
     /// [Controller]: crate::prelude::Controller
-    fn register_command(&self, interest: Interest, command: Rc<dyn Command<Body>>);
+    fn register_command(&self, interest: Interest, command: Shared<dyn Command<Body>>);
+
+    /// Register an [AsyncCommand] with the [Controller].
+    ///
+    /// [Controller]: crate::prelude::Controller
+    fn register_async_command(&self, interest: Interest, command: Shared<dyn AsyncCommand<Body>>);
+
+    /// Register an [UndoableCommand] with the [Controller].
+    ///
+    /// [Controller]: crate::prelude::Controller
+    fn register_undoable_command(&self, interest: Interest, command: Shared<dyn UndoableCommand<Body>>);
 
     /// Remove a previously registered [Command] to [Notification] mapping from the [Controller].
     /// 
@@ -38,8 +48,25 @@ where
     /// [Notification]: crate::prelude::Notification
     fn has_command(&self, interest: &Interest) -> bool;
 
+    /// Register a [Middleware] at the end of the chain,
+    /// which the sent [Notification]'s pass through.
+    ///
+    /// [Notification]: crate::prelude::Notification
+    fn register_middleware(&self, middleware: Shared<dyn Middleware<Body>>);
+
     /// Create and send an [Notification].
     /// 
     /// [Notification]: crate::prelude::Notification
     fn send(&self, interest: Interest, body: Option<Body>);
+
+    /// Create and send an [Notification], the returned future
+    /// resolves when every triggered [AsyncCommand] has finished.
+    ///
+    /// The [Command]'s and [Observer]'s are notified before this method returns,
+    /// the [AsyncCommand]'s are driven by the returned future instead of the [Spawner].
+    ///
+    /// [Notification]: crate::prelude::Notification
+    /// [Observer]: crate::prelude::Observer
+    /// [Spawner]: crate::prelude::Spawner
+    fn send_async(&self, interest: Interest, body: Option<Body>) -> BoxFuture<'static, ()>;
 }
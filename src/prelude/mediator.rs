@@ -1,23 +1,23 @@
-use std::{any::Any, fmt::Debug, rc::Rc};
+use std::{any::Any, fmt::Debug};
 
-use super::{Interest, Notification, NotifyContext, View};
+use super::{CoreKey, Interest, Notification, NotifyContext, Priority, Shared, View, DEFAULT_PRIORITY};
 
 /// The definition for a PureMVC [Mediator].
 ///
 /// In PureMVC, [Mediator] implementors assume these responsibilities:
 ///
 /// - Implement a common method which returns a list of all [Notification]'s
-/// the [Mediator] has interest in.
+///   the [Mediator] has interest in.
 /// - Implement a common notification (callback) method.
 ///
 /// Additionally, [Mediator]'s typically:
 ///
 /// - Act as an intermediary between one or more view components such as text boxes or
-/// list controls, maintaining references and coordinating their behavior.
+///   list controls, maintaining references and coordinating their behavior.
 /// - In Flash-based apps, this is often the place where event listeners are
-/// added to view components, and their handlers implemented.
+///   added to view components, and their handlers implemented.
 /// - Respond to and generate [Notification]'s, interacting with of
-/// the rest of the PureMVC app.
+///   the rest of the PureMVC app.
 ///
 /// When an [Mediator] is registered with the [View],
 /// the [View] will call the [Mediator]'s
@@ -33,22 +33,37 @@ use super::{Interest, Notification, NotifyContext, View};
 /// [Observer]: crate::prelude::Observer
 /// [handle_notification]: Mediator::handle_notification
 /// [list_notification_interests]: Mediator::list_notification_interests
-
 pub trait Mediator<Body>: NotifyContext + Debug + Sized + Any
 where
     Body: Debug + 'static,
 {
     /// Get the [Mediator]'s view component.
-    fn view_component(&self) -> Option<Rc<dyn View<Body>>>;
+    fn view_component(&self) -> Option<Shared<dyn View<Body>>>;
 
     /// Set the [Mediator]'s view component.
-    fn set_view_component(&mut self, component: Option<Rc<dyn View<Body>>>);
+    fn set_view_component(&mut self, component: Option<Shared<dyn View<Body>>>);
 
     /// List [Notification] interests.
     fn list_notification_interests(&self) -> &[Interest];
 
     /// Handle an [Notification].
-    fn handle_notification(&self, notification: Rc<dyn Notification<Body>>);
+    fn handle_notification(&self, notification: Shared<dyn Notification<Body>>);
+
+    /// The [Priority] the [Mediator] is notified with.
+    ///
+    /// Return a positive priority to handle the [Notification]'s before the [Command]'s,
+    /// or a negative one to handle them after.
+    ///
+    /// [Command]: crate::prelude::Command
+    fn notification_priority(&self) -> Priority {
+        DEFAULT_PRIORITY
+    }
+
+    /// Initialize the [Mediator] with the multiton key of the Core.
+    ///
+    /// Called by the [View] before [on_register](Mediator::on_register),
+    /// so the [Mediator] is able to send [Notification]'s through its Core.
+    fn initialize_notifier(&self, _key: &CoreKey) {}
 
     /// Called by the [View] when the [Mediator] is registered
     fn on_register(&self);
@@ -0,0 +1,87 @@
+use std::fmt::Debug;
+
+use super::{CoreKey, Interest, MaybeSendSync, Notification, Shared};
+
+/// The definition for a typed Message.
+///
+/// The [Message] ties the type of the [Notification] body to its [Interest],
+/// so the typed messages are sent with [send_typed](TypedFacade::send_typed)
+/// and received by the [TypedCommand]'s without matching the body by hand.
+///
+/// The Core actors stay generic over one `Body`, which carries the bodies of
+/// all of the [Message]'s, see [TypedBody].
+pub trait Message: 'static {
+    /// The type of the body of the [Message]
+    type Body: Debug + MaybeSendSync + 'static;
+
+    /// The [Interest] the [Message] is sent with
+    const INTEREST: Interest;
+}
+
+/// The definition for the `Body` of the Core, which carries the bodies of the [Message]'s.
+///
+/// Implement it for your own `Body` enum, or use the built-in [AnyBody].
+///
+/// [AnyBody]: crate::foundation::patterns::message::AnyBody
+pub trait TypedBody: Debug + Sized + 'static {
+    /// Wrap the body of the [Message]
+    fn from_message<M: Message>(body: M::Body) -> Self;
+
+    /// Retrieve the body of the [Message], if this is the one
+    fn message<M: Message>(&self) -> Option<&M::Body>;
+}
+
+/// The definition for a Command which handles a typed [Message].
+pub trait TypedCommand<M: Message>: Debug + MaybeSendSync {
+    /// Initialize the [TypedCommand] with the multiton key of the Core.
+    ///
+    /// Called by the [Controller] when the [TypedCommand] is registered.
+    ///
+    /// [Controller]: crate::prelude::Controller
+    fn initialize_notifier(&self, _key: &CoreKey) {}
+
+    /// Execute the [TypedCommand]'s logic to handle the [Message].
+    fn execute(&self, message: &M::Body);
+}
+
+/// Defines the typed [Message] functionality of the [Facade].
+///
+/// [Facade]: crate::prelude::Facade
+pub trait TypedFacade<Body>
+where
+    Body: TypedBody,
+{
+    /// Register the [TypedCommand] for the [Interest] of the [Message].
+    ///
+    /// The [TypedCommand] is registered as any other Command, so it is able to be
+    /// checked and removed by the [Interest] of the [Message].
+    fn register_typed_command<M: Message>(&self, command: Shared<dyn TypedCommand<M>>);
+
+    /// Create and send the [Notification] of the [Message].
+    fn send_typed<M: Message>(&self, body: M::Body);
+}
+
+/// Retrieve the typed [Message] from the [Notification].
+///
+/// Allows the Mediators to receive the [Message]'s in their
+/// [handle_notification](crate::prelude::Mediator::handle_notification).
+pub trait TypedNotification<Body>
+where
+    Body: TypedBody,
+{
+    /// Retrieve the body of the [Message], if the [Notification] is sent for it.
+    fn message<M: Message>(&self) -> Option<&M::Body>;
+}
+
+impl<Body> TypedNotification<Body> for dyn Notification<Body>
+where
+    Body: TypedBody,
+{
+    fn message<M: Message>(&self) -> Option<&M::Body> {
+        if self.interest() != M::INTEREST {
+            return None;
+        }
+
+        self.body().and_then(TypedBody::message::<M>)
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::Debug;
+
+use super::{MaybeSendSync, Notification, Shared, View};
+
+/// The definition for a Middleware of the [Facade].
+///
+/// The [Middleware]'s are called in the order they are registered,
+/// each time a [Notification] is sent through the [Facade], before
+/// the [Notification] is broadcast by the [View].
+///
+/// A [Middleware] passes the [Notification] to the rest of the chain
+/// with [Next::run]. So it is able to log, transform, delay, drop or
+/// fan out the [Notification]'s.
+///
+/// [Facade]: crate::prelude::Facade
+/// [View]: crate::prelude::View
+pub trait Middleware<Body>: Debug + MaybeSendSync
+where
+    Body: Debug + 'static,
+{
+    /// Handle the [Notification] on its way to the [View].
+    ///
+    /// The [Notification] is dropped unless `next` is run.
+    ///
+    /// [View]: crate::prelude::View
+    fn handle(&self, notification: Shared<dyn Notification<Body>>, next: Next<Body>);
+}
+
+/// The rest of the [Middleware] chain.
+///
+/// It is able to be cloned and kept, to run the chain later.
+pub struct Next<Body>
+where
+    Body: Debug + 'static,
+{
+    chain: Shared<[Shared<dyn Middleware<Body>>]>,
+    index: usize,
+    view: Shared<dyn View<Body>>,
+}
+
+impl<Body> Next<Body>
+where
+    Body: Debug + 'static,
+{
+    /// Create the chain of the [Middleware]'s, which ends with notifying the [View]
+    pub fn new(chain: Vec<Shared<dyn Middleware<Body>>>, view: Shared<dyn View<Body>>) -> Self {
+        Self {
+            chain: chain.into(),
+            index: 0,
+            view,
+        }
+    }
+
+    /// Pass the [Notification] to the rest of the chain
+    pub fn run(&self, notification: Shared<dyn Notification<Body>>) {
+        match self.chain.get(self.index) {
+            Some(middleware) => middleware.handle(
+                notification,
+                Self {
+                    chain: self.chain.clone(),
+                    index: self.index + 1,
+                    view: self.view.clone(),
+                },
+            ),
+            None => self.view.notify(notification),
+        }
+    }
+}
+
+impl<Body> Clone for Next<Body>
+where
+    Body: Debug + 'static,
+{
+    fn clone(&self) -> Self {
+        Self {
+            chain: self.chain.clone(),
+            index: self.index,
+            view: self.view.clone(),
+        }
+    }
+}
+
+impl<Body> Debug for Next<Body>
+where
+    Body: Debug + 'static,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Next")
+            .field("remaining", &self.chain[self.index..].len())
+            .finish()
+    }
+}
@@ -23,9 +23,18 @@ pub use self::facade::*;
 mod mediator;
 pub use self::mediator::*;
 
+mod message;
+pub use self::message::*;
+
+mod middleware;
+pub use self::middleware::*;
+
 mod model;
 pub use self::model::*;
 
+mod multiton;
+pub use self::multiton::*;
+
 mod notification;
 pub use self::notification::*;
 
@@ -38,8 +47,17 @@ pub use self::observer::*;
 mod proxy;
 pub use self::proxy::*;
 
+mod shared;
+pub use self::shared::*;
+
 mod singleton;
 pub use self::singleton::*;
 
+mod spawner;
+pub use self::spawner::*;
+
+mod storage;
+pub use self::storage::*;
+
 mod view;
 pub use self::view::*;
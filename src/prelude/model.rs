@@ -1,6 +1,4 @@
-use std::rc::Rc;
-
-use super::Proxy;
+use super::{Proxy, Shared};
 
 /// The definition for a PureMVC Model.
 ///
@@ -12,17 +10,35 @@ use super::Proxy;
 /// - Maintain a cache of [Proxy] instances
 /// - Provide methods for registering, retrieving, and removing [Proxy] instances
 ///
-
 pub trait Model {
     /// Register an [Proxy] instance with the [Model].
-    fn register_proxy<P: Proxy>(&self, proxy: Rc<P>);
+    fn register_proxy<P: Proxy>(&self, proxy: Shared<P>);
 
     /// Retrieve an [Proxy] instance from the Model.
-    fn retrieve_proxy<P: Proxy>(&self) -> Option<Rc<P>>;
+    fn retrieve_proxy<P: Proxy>(&self) -> Option<Shared<P>>;
 
     /// Remove an [Proxy] instance from the Model.
-    fn remove_proxy<P: Proxy>(&self) -> Option<Rc<P>>;
+    fn remove_proxy<P: Proxy>(&self) -> Option<Shared<P>>;
 
     /// Check if a [Proxy] is registered
     fn has_proxy<P: Proxy>(&self) -> bool;
+
+    /// Register an [Proxy] instance with the [Model] by name.
+    ///
+    /// The named [Proxy]'s are kept apart from the ones registered by type,
+    /// so several instances of the same type are able to be registered.
+    /// The [Proxy] replaces the one previously registered with the name, which is removed.
+    fn register_named_proxy<P: Proxy>(&self, name: &str, proxy: Shared<P>);
+
+    /// Retrieve an [Proxy] instance from the Model by name, `None` if it is not of the type.
+    fn retrieve_named_proxy<P: Proxy>(&self, name: &str) -> Option<Shared<P>>;
+
+    /// Remove an [Proxy] instance from the Model by name, it is kept if it is not of the type.
+    fn remove_named_proxy<P: Proxy>(&self, name: &str) -> Option<Shared<P>>;
+
+    /// Check if a [Proxy] is registered with the name
+    fn has_named_proxy(&self, name: &str) -> bool;
+
+    /// List the names of the registered [Proxy]'s in order
+    fn proxy_names(&self) -> Vec<String>;
 }
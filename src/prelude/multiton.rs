@@ -0,0 +1,47 @@
+use std::fmt;
+
+use super::Shared;
+
+/// Represent the key of a multiton Core
+#[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CoreKey(pub String);
+
+impl CoreKey {
+    /// Retrieve the key as string slice
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for CoreKey {
+    fn from(key: &str) -> Self {
+        Self(key.to_string())
+    }
+}
+
+impl From<String> for CoreKey {
+    fn from(key: String) -> Self {
+        Self(key)
+    }
+}
+
+impl From<&CoreKey> for CoreKey {
+    fn from(key: &CoreKey) -> Self {
+        key.clone()
+    }
+}
+
+impl fmt::Display for CoreKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Multiton Factory method
+///
+/// Each Core is identified by its [CoreKey], so several independent
+/// Cores may live side by side (per plugin, per test, per document).
+pub trait Multiton {
+    /// Retrieve instance of Multiton for the given key, creating it on first access
+    fn instance(key: impl Into<CoreKey>) -> Shared<Self>;
+}
@@ -1,9 +1,69 @@
 use std::fmt;
 
+use super::MaybeSendSync;
+
 /// Represent [Notification]'s interest
+///
+/// The high 16 bits of the interest are its category, so the interests
+/// are able to be namespaced and observed by category with [InterestPattern].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Interest(pub u64);
 
+impl Interest {
+    /// The position of the category bits
+    pub const CATEGORY_SHIFT: u32 = 48;
+
+    /// The bits of the category
+    pub const CATEGORY_MASK: u64 = 0xFFFF << Self::CATEGORY_SHIFT;
+
+    /// Create the interest with the code in the given category
+    pub const fn new(category: u16, code: u64) -> Self {
+        Self(((category as u64) << Self::CATEGORY_SHIFT) | (code & !Self::CATEGORY_MASK))
+    }
+
+    /// Retrieve the category of the interest
+    pub const fn category(&self) -> u16 {
+        (self.0 >> Self::CATEGORY_SHIFT) as u16
+    }
+
+    /// Retrieve the code of the interest within its category
+    pub const fn code(&self) -> u64 {
+        self.0 & !Self::CATEGORY_MASK
+    }
+}
+
+/// Matches the [Interest]'s of the [Notification]'s observed by a wildcard [Observer].
+///
+/// [Observer]: crate::prelude::Observer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum InterestPattern {
+    /// Match all of the interests
+    All,
+    /// Match the interests of the category
+    Category(u16),
+    /// Match the interests in the inclusive range
+    Range(Interest, Interest),
+    /// Match the interests which have the `value` bits under the `mask`
+    Mask {
+        /// The matched bits
+        mask: u64,
+        /// The value of the matched bits
+        value: u64,
+    },
+}
+
+impl InterestPattern {
+    /// Check if the pattern matches the interest
+    pub fn matches(&self, interest: Interest) -> bool {
+        match *self {
+            Self::All => true,
+            Self::Category(category) => interest.category() == category,
+            Self::Range(start, end) => start <= interest && interest <= end,
+            Self::Mask { mask, value } => interest.0 & mask == value & mask,
+        }
+    }
+}
+
 /// The definition for a PureMVC Notification.
 ///
 /// PureMVC does not rely upon underlying event models such
@@ -36,8 +96,7 @@ pub struct Interest(pub u64);
 /// [Mediator]: crate::prelude::Mediator
 /// [Proxy]: crate::prelude::Proxy
 /// [Command]: crate::prelude::Command
-
-pub trait Notification<Body>: fmt::Debug
+pub trait Notification<Body>: fmt::Debug + MaybeSendSync
 where
     Body: fmt::Debug + 'static,
 {
@@ -13,7 +13,7 @@ use super::Interest;
 ///
 /// The [Notifier] class, which all of the above mentioned classes
 /// extend, also provides an initialized reference to the [Facade]
-/// Singleton, which is required for the convienience method
+/// Multiton, which is required for the convienience method
 /// for sending [Notification]'s, but also eases implementation as these
 /// classes have frequent [Facade] interactions and usually require
 /// access to the facade anyway.
@@ -25,7 +25,6 @@ use super::Interest;
 /// [Mediator]: crate::prelude::Mediator
 /// [Proxy]: crate::prelude::Proxy
 /// [send]: Notifier::send
-
 pub trait Notifier<Body>
 where
     Body: Debug + 'static,
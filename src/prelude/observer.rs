@@ -1,13 +1,36 @@
-use std::{fmt::Debug, rc::Rc};
+use std::fmt::Debug;
 
-use super::Notification;
+use super::{MaybeSendSync, Notification, Shared};
 
 /// Defines NotifyContext identity
-pub trait NotifyContext: Debug {
+pub trait NotifyContext: Debug + MaybeSendSync {
     /// Retrieve context identity
     fn id(&self) -> u64;
 }
 
+/// Defines whether the [Notification] is passed to the rest of the [Observer]'s
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Propagation {
+    /// Notify the rest of the [Observer]'s
+    Continue,
+    /// Stop the notification, the rest of the [Observer]'s are not notified
+    Stop,
+}
+
+/// The priority of an [Observer], the [Observer]'s with higher priorities are notified first
+pub type Priority = i32;
+
+/// The default priority of the [Observer]'s
+pub const DEFAULT_PRIORITY: Priority = 0;
+
+/// The notification method of an [Observer]
+#[cfg(not(feature = "sync"))]
+pub type NotifyMethod<Body> = Box<dyn Fn(Shared<dyn Notification<Body>>) -> Propagation>;
+
+/// The notification method of an [Observer]
+#[cfg(feature = "sync")]
+pub type NotifyMethod<Body> = Box<dyn Fn(Shared<dyn Notification<Body>>) -> Propagation + Send + Sync>;
+
 /// The definition for a PureMVC Observer.
 ///
 /// In PureMVC, [Observer] implementors assume these responsibilities:
@@ -34,25 +57,26 @@ pub trait NotifyContext: Debug {
 /// [notify](Observer::notify) method invoked, passing
 /// in an object implementing the [Notification] interface, such
 /// as a subclass of [Notification].
-
-pub trait Observer<Body>: Debug
+pub trait Observer<Body>: Debug + MaybeSendSync
 where
     Body: Debug + 'static,
 {
     /// Get the notification context.
-    fn context(&self) -> &Rc<dyn NotifyContext>;
+    fn context(&self) -> &Shared<dyn NotifyContext>;
 
     /// Set the notification method.
     ///
     /// The notification method should take one parameter of type [Notification]
-    fn set_method(&mut self, notify_method: Box<dyn Fn(Rc<dyn Notification<Body>>)>);
+    fn set_method(&mut self, notify_method: NotifyMethod<Body>);
 
     /// Set the notification context.
-    fn set_context(&mut self, notify_context: Rc<dyn NotifyContext>);
+    fn set_context(&mut self, notify_context: Shared<dyn NotifyContext>);
 
     /// Notify the interested object.
-    fn notify(&self, notification: Rc<dyn Notification<Body>>);
+    ///
+    /// Returns [Propagation::Stop] to keep the rest of the [Observer]'s from being notified.
+    fn notify(&self, notification: Shared<dyn Notification<Body>>) -> Propagation;
 
     /// Compare the given object to the notificaiton context object.
-    fn compare_context(&self, object: &Rc<dyn NotifyContext>) -> bool;
+    fn compare_context(&self, object: &Shared<dyn NotifyContext>) -> bool;
 }
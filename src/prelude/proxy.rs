@@ -1,5 +1,9 @@
 use std::{any::Any, fmt::Debug};
 
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{CoreKey, MaybeSendSync};
+
 /// The definition for a PureMVC Proxy.
 ///
 /// In PureMVC, [Proxy] implementors assume these responsibilities:
@@ -17,11 +21,39 @@ use std::{any::Any, fmt::Debug};
 /// [Notification]: crate::prelude::Notification
 /// [Debug]: std::fmt::Debug
 /// [Display]: std::fmt::Display
+pub trait Proxy: Debug + Sized + Any + MaybeSendSync {
+    /// Initialize the [Proxy] with the multiton key of the Core.
+    ///
+    /// Called by the Model before [on_register](Proxy::on_register),
+    /// so the [Proxy] is able to send [Notification](crate::prelude::Notification)'s through its Core.
+    fn initialize_notifier(&self, _key: &CoreKey) {}
 
-pub trait Proxy: Debug + Sized + Any {
     /// Called by the Model when the [Proxy] is registered
     fn on_register(&self);
 
     /// Called by the Model when the [Proxy] is removed
     fn on_remove(&self);
 }
+
+/// A [Proxy] which opts in to the snapshot of the Model.
+///
+/// The proxies registered with [register_snapshot_proxy] are captured into the single
+/// document by [snapshot] and rehydrated from it by [restore], so the state of the application
+/// is able to be recovered after the crash or attached to the bug report.
+///
+/// [register_snapshot_proxy]: crate::foundation::patterns::default::BaseModel::register_snapshot_proxy
+/// [snapshot]: crate::foundation::patterns::default::BaseModel::snapshot
+/// [restore]: crate::foundation::patterns::default::BaseModel::restore
+pub trait SnapshotProxy: Proxy {
+    /// The name of the [Proxy] in the snapshot, which is stable across the builds unlike the `TypeId`
+    const NAME: &'static str;
+
+    /// The captured data of the [Proxy]
+    type Snapshot: Serialize + DeserializeOwned + 'static;
+
+    /// Capture the data of the [Proxy]
+    fn snapshot(&self) -> Self::Snapshot;
+
+    /// Put the captured data back into the [Proxy]
+    fn restore(&self, snapshot: Self::Snapshot);
+}
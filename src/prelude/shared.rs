@@ -0,0 +1,101 @@
+//! Shared ownership primitives of the Core actors.
+//!
+//! By default the Core actors are single threaded and built on top of [Rc] and [RefCell].
+//! With the `sync` feature enabled the same actors are built on top of [Arc] and [RwLock],
+//! and every [Notification], [Observer], [Command], [Proxy] and [Mediator] is required
+//! to be `Send + Sync`, so notifications can be sent from worker threads.
+//! The futures of the [AsyncCommand]'s are required to be `Send` as well.
+//!
+//! [Rc]: std::rc::Rc
+//! [RefCell]: std::cell::RefCell
+//! [Arc]: std::sync::Arc
+//! [RwLock]: std::sync::RwLock
+//! [Notification]: crate::prelude::Notification
+//! [Observer]: crate::prelude::Observer
+//! [Command]: crate::prelude::Command
+//! [AsyncCommand]: crate::prelude::AsyncCommand
+//! [Proxy]: crate::prelude::Proxy
+//! [Mediator]: crate::prelude::Mediator
+
+#[cfg(not(feature = "sync"))]
+mod inner {
+    use std::{any::Any, cell::RefCell, future::Future, pin::Pin, rc::Rc};
+
+    /// Shared pointer of the Core actors
+    pub type Shared<T> = Rc<T>;
+
+    /// Interior mutability of the Core actors
+    pub type Lock<T> = RefCell<T>;
+
+    /// Marker of types which are able to be shared by the Core actors
+    pub trait MaybeSendSync {}
+
+    impl<T: ?Sized> MaybeSendSync for T {}
+
+    pub(crate) type SharedAny = Rc<dyn Any>;
+
+    /// Boxed future of the asynchronous Core actors
+    pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + 'a>>;
+}
+
+#[cfg(feature = "sync")]
+mod inner {
+    use std::{
+        any::Any,
+        fmt,
+        future::Future,
+        pin::Pin,
+        sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard},
+    };
+
+    /// Shared pointer of the Core actors
+    pub type Shared<T> = Arc<T>;
+
+    /// Marker of types which are able to be shared by the Core actors
+    pub trait MaybeSendSync: Send + Sync {}
+
+    impl<T: ?Sized + Send + Sync> MaybeSendSync for T {}
+
+    pub(crate) type SharedAny = Arc<dyn Any + Send + Sync>;
+
+    /// Boxed future of the asynchronous Core actors
+    pub type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
+
+    /// Interior mutability of the Core actors
+    ///
+    /// Mirrors the [RefCell](std::cell::RefCell) interface on top of [RwLock],
+    /// a poisoned lock is still accessible, as the Core actors keep no invariants
+    /// across the lock.
+    #[derive(Default)]
+    pub struct Lock<T>(RwLock<T>);
+
+    impl<T> Lock<T> {
+        /// Create new lock
+        pub fn new(value: T) -> Self {
+            Self(RwLock::new(value))
+        }
+
+        /// Immutably borrows the wrapped value
+        pub fn borrow(&self) -> RwLockReadGuard<'_, T> {
+            self.0.read().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// Mutably borrows the wrapped value
+        pub fn borrow_mut(&self) -> RwLockWriteGuard<'_, T> {
+            self.0.write().unwrap_or_else(PoisonError::into_inner)
+        }
+
+        /// Replaces the wrapped value with a new one, returning the old value
+        pub fn replace(&self, value: T) -> T {
+            std::mem::replace(&mut *self.borrow_mut(), value)
+        }
+    }
+
+    impl<T: fmt::Debug> fmt::Debug for Lock<T> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.debug_tuple("Lock").field(&*self.borrow()).finish()
+        }
+    }
+}
+
+pub use self::inner::*;
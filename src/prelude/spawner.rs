@@ -0,0 +1,16 @@
+use super::{BoxFuture, MaybeSendSync};
+
+/// The definition of an executor which drives the [AsyncCommand]'s.
+///
+/// The Core actors are not tied to any async runtime, the [Controller]
+/// hands over the futures of the [AsyncCommand]'s to its [Spawner].
+/// Implement it on top of the runtime of your application, or use
+/// the built-in [LocalExecutor].
+///
+/// [AsyncCommand]: crate::prelude::AsyncCommand
+/// [Controller]: crate::prelude::Controller
+/// [LocalExecutor]: crate::foundation::patterns::command::LocalExecutor
+pub trait Spawner: MaybeSendSync {
+    /// Spawn the future to be driven to completion in background.
+    fn spawn(&self, future: BoxFuture<'static, ()>);
+}
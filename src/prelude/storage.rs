@@ -0,0 +1,23 @@
+use std::io;
+
+use super::MaybeSendSync;
+
+/// The definition of a key-value backend which persists the [PersistentProxy]'s.
+///
+/// The documents are stored as strings, so the backend does not depend on
+/// the Data of the proxies. Use the built-in [MemoryStorage] and [JsonFileStorage],
+/// or implement it on top of the storage of your application.
+///
+/// [PersistentProxy]: crate::foundation::patterns::proxy::PersistentProxy
+/// [MemoryStorage]: crate::foundation::patterns::proxy::MemoryStorage
+/// [JsonFileStorage]: crate::foundation::patterns::proxy::JsonFileStorage
+pub trait Storage: MaybeSendSync {
+    /// Load the document stored with the key, `None` if there is no such document
+    fn load(&self, key: &str) -> io::Result<Option<String>>;
+
+    /// Store the document with the key, replacing the previous one
+    fn save(&self, key: &str, document: &str) -> io::Result<()>;
+
+    /// Remove the document stored with the key
+    fn remove(&self, key: &str) -> io::Result<()>;
+}
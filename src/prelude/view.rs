@@ -1,6 +1,8 @@
-use std::{fmt::Debug, rc::Rc};
+use std::fmt::Debug;
 
-use super::{Interest, Mediator, Notification, NotifyContext, Observer};
+use super::{
+    Interest, InterestPattern, MaybeSendSync, Mediator, Notification, NotifyContext, Observer, Priority, Shared,
+};
 
 /// The definition for a PureMVC View.
 ///
@@ -13,23 +15,36 @@ use super::{Interest, Mediator, Notification, NotifyContext, Observer};
 /// - Providing a method for broadcasting an [Notification].
 /// - Notifying the [Observer]'s of a given [Notification] when it broadcast.
 ///
-
-pub trait View<Body>
+pub trait View<Body>: MaybeSendSync
 where
     Body: Debug + 'static,
 {
     /// Register an [Observer] to be notified of [Notification]'s with a given name.
-    fn register_observer(&self, interest: Interest, observer: Rc<dyn Observer<Body>>);
+    fn register_observer(&self, interest: Interest, observer: Shared<dyn Observer<Body>>);
+
+    /// Register an [Observer] with the given [Priority].
+    ///
+    /// The [Observer]'s with higher priorities are notified first,
+    /// the ones with the same priority are notified in the order in which they were registered.
+    /// The priority is ignored by default.
+    fn register_observer_with_priority(
+        &self,
+        interest: Interest,
+        observer: Shared<dyn Observer<Body>>,
+        _priority: Priority,
+    ) {
+        self.register_observer(interest, observer);
+    }
 
     /// Remove a group of observers from the observer list for a given Notification name.
-    fn remove_observer(&self, interest: &Interest, notify_context: &Rc<dyn NotifyContext>);
+    fn remove_observer(&self, interest: &Interest, notify_context: &Shared<dyn NotifyContext>);
 
     /// Notify the [Observer]'s for a particular [Notification].
     ///
     /// All previously attached [Observer]'s for this [Notification]'s
     /// list are notified and are passed a reference to the [Notification] in
-    /// the order in which they were registered.
-    fn notify(&self, note: Rc<dyn Notification<Body>>);
+    /// the order of their priorities, until one of them stops the propagation.
+    fn notify(&self, note: Shared<dyn Notification<Body>>);
 }
 
 
@@ -48,14 +63,54 @@ where
     /// the [Mediator] instance's [handle_notification](Mediator::handle_notification) method
     /// and registering it as an [Observer] for all [Notification]'s the
     /// [Mediator] is interested in.
-    fn register_mediator<M: Mediator<Body>>(&self, mediator: Rc<M>);
+    fn register_mediator<M: Mediator<Body>>(&self, mediator: Shared<M>);
 
     /// Retrieve an [Mediator] from the [View].
-    fn retrieve_mediator<M: Mediator<Body>>(&self) -> Option<Rc<M>>;
+    fn retrieve_mediator<M: Mediator<Body>>(&self) -> Option<Shared<M>>;
 
     /// Remove an [Mediator] from the [View].
-    fn remove_mediator<M: Mediator<Body>>(&self) -> Option<Rc<M>>;
+    fn remove_mediator<M: Mediator<Body>>(&self) -> Option<Shared<M>>;
 
     /// Check if a [Mediator] is registered or not
     fn has_mediator<M: Mediator<Body>>(&self) -> bool;
+
+    /// Register an [Mediator] instance with the [View] by name.
+    ///
+    /// The named [Mediator]'s are kept apart from the ones registered by type,
+    /// so several instances of the same type are able to be registered.
+    /// The [Mediator] replaces the one previously registered with the name, which is removed.
+    fn register_named_mediator<M: Mediator<Body>>(&self, name: &str, mediator: Shared<M>);
+
+    /// Retrieve an [Mediator] from the [View] by name, `None` if it is not of the type.
+    fn retrieve_named_mediator<M: Mediator<Body>>(&self, name: &str) -> Option<Shared<M>>;
+
+    /// Remove an [Mediator] from the [View] by name, it is kept if it is not of the type.
+    fn remove_named_mediator<M: Mediator<Body>>(&self, name: &str) -> Option<Shared<M>>;
+
+    /// Check if a [Mediator] is registered with the name
+    fn has_named_mediator(&self, name: &str) -> bool;
+
+    /// List the names of the registered [Mediator]'s in order
+    fn mediator_names(&self) -> Vec<String>;
+}
+
+/// Defines wildcard [Observer] functionality
+///
+/// The wildcard [Observer]'s are notified of every [Notification] their [InterestPattern]
+/// matches, together with the [Observer]'s of its exact [Interest], in the order of
+/// their priorities.
+pub trait PatternRegistry<Body>
+where
+    Body: Debug + 'static,
+{
+    /// Register an [Observer] to be notified of [Notification]'s matching the pattern.
+    fn register_pattern_observer(
+        &self,
+        pattern: InterestPattern,
+        observer: Shared<dyn Observer<Body>>,
+        priority: Priority,
+    );
+
+    /// Remove the [Observer] of the notify context from the observers of the pattern.
+    fn remove_pattern_observer(&self, pattern: &InterestPattern, notify_context: &Shared<dyn NotifyContext>);
 }